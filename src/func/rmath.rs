@@ -1,6 +1,6 @@
 use crate::errhuman::{TransRError, TransRResult};
-use num_bigint::{BigInt, ToBigInt};
-use num_traits::{Zero, One, ToPrimitive};
+use num_bigint::{BigInt, RandBigInt, ToBigInt};
+use num_traits::{Signed, Zero, One, ToPrimitive};
 use rand::Rng;
 
 pub struct RMath;
@@ -183,6 +183,112 @@ impl RMath {
         true
     }
     
+    // Reduces `value` into `[0, modulus)`, unlike Rust's `%` which can
+    // return a negative remainder for a negative dividend.
+    fn floor_mod(value: &BigInt, modulus: &BigInt) -> BigInt {
+        let remainder = value % modulus;
+        if remainder.is_negative() {
+            remainder + modulus
+        } else {
+            remainder
+        }
+    }
+
+    // `base^exp mod modulus` via square-and-multiply, walking `exp`'s bits
+    // from most to least significant.
+    pub fn modpow(base: &BigInt, exp: &BigInt, modulus: &BigInt) -> BigInt {
+        if modulus.is_one() {
+            return BigInt::zero();
+        }
+
+        let base = Self::floor_mod(base, modulus);
+        let mut result = BigInt::one();
+        let exp_magnitude = exp.magnitude();
+
+        for bit in (0..exp_magnitude.bits()).rev() {
+            result = Self::floor_mod(&(&result * &result), modulus);
+            if exp_magnitude.bit(bit) {
+                result = Self::floor_mod(&(&result * &base), modulus);
+            }
+        }
+
+        result
+    }
+
+    // Extended Euclidean algorithm: the `x` such that `a * x ≡ 1 (mod modulus)`.
+    pub fn mod_inverse(a: &BigInt, modulus: &BigInt) -> TransRResult<BigInt> {
+        let (mut old_r, mut r) = (a.clone(), modulus.clone());
+        let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+
+        while !r.is_zero() {
+            let quotient = &old_r / &r;
+
+            let next_r = &old_r - &quotient * &r;
+            old_r = r;
+            r = next_r;
+
+            let next_s = &old_s - &quotient * &s;
+            old_s = s;
+            s = next_s;
+        }
+
+        if old_r != BigInt::one() && old_r != -BigInt::one() {
+            return Err(TransRError::MathError(
+                format!("{} has no modular inverse mod {} (gcd = {})", a, modulus, old_r)
+            ));
+        }
+
+        Ok(Self::floor_mod(&old_s, modulus))
+    }
+
+    // Miller-Rabin probabilistic primality test for numbers too large for
+    // trial division. `rounds` random witnesses give a false-positive rate
+    // below `4^-rounds`.
+    pub fn miller_rabin(n: &BigInt, rounds: u32) -> bool {
+        let two = BigInt::from(2);
+
+        if *n < two {
+            return false;
+        }
+        if *n == two || *n == BigInt::from(3) {
+            return true;
+        }
+        if (n % &two).is_zero() {
+            return false;
+        }
+
+        // Write n - 1 = 2^s * d with d odd.
+        let n_minus_one = n - BigInt::one();
+        let mut d = n_minus_one.clone();
+        let mut s = 0u32;
+        while (&d % &two).is_zero() {
+            d /= &two;
+            s += 1;
+        }
+
+        let mut rng = rand::thread_rng();
+
+        'witness: for _ in 0..rounds {
+            let witness = rng.gen_bigint_range(&two, &(n - BigInt::one()));
+            let mut x = Self::modpow(&witness, &d, n);
+
+            if x == BigInt::one() || x == n_minus_one {
+                continue;
+            }
+
+            for _ in 0..s.saturating_sub(1) {
+                x = Self::modpow(&x, &two, n);
+                if x == n_minus_one {
+                    continue 'witness;
+                }
+            }
+
+            return false;
+        }
+
+        true
+    }
+
     pub fn fibonacci(n: usize) -> Vec<u64> {
         if n == 0 {
             return vec![];
@@ -1,9 +1,12 @@
 use crate::errhuman::{TransRError, TransRResult};
 use crate::vvv::Verbose;
-use reqwest::blocking::Client;
+use rand::Rng;
+use reqwest::{RequestBuilder, Response as RawResponse};
 use serde::{Serialize, Deserialize};
-use std::time::Duration;
+use sha2::{Sha256, Digest};
+use std::time::{Duration, Instant};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Response {
@@ -13,31 +16,298 @@ pub struct Response {
     pub url: String,
 }
 
+// Exponential backoff with full jitter: attempt `n` sleeps a random
+// duration in `[0, base * 2^n]`, capped at `max_backoff_ms`.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_backoff_ms: 200,
+            max_backoff_ms: 5_000,
+        }
+    }
+}
+
+// Response cache keyed by method+URL+body-hash, entries expiring after `ttl`.
+struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Response)>>,
+}
+
+// Blocking-looking client kept for callers that don't want to deal with
+// `async`/`await`; internally it's a thin wrapper that drives `AsyncRNet`
+// on the shared Tokio runtime via `block_on`, so there's one real HTTP
+// implementation (retry/cache live here, on top of that shared core).
 pub struct RNet {
-    client: Client,
+    async_client: AsyncRNet,
+    retry: Option<RetryPolicy>,
+    cache: Option<ResponseCache>,
 }
 
 impl RNet {
     pub fn new() -> TransRResult<Self> {
-        let client = Client::builder()
+        Ok(RNet { async_client: AsyncRNet::new()?, retry: None, cache: None })
+    }
+
+    // Opts this client into retrying transport errors and 5xx/429
+    // responses with exponential backoff and full jitter.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    // Opts this client into caching `get`/`post`/`put`/`delete` responses
+    // for `ttl`, keyed by method+URL+body-hash.
+    pub fn with_cache(mut self, ttl: Duration) -> Self {
+        self.cache = Some(ResponseCache { ttl, entries: Mutex::new(HashMap::new()) });
+        self
+    }
+
+    fn cache_key(method: &str, url: &str, body: Option<&str>) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(body.unwrap_or("").as_bytes());
+        format!("{}:{}:{:x}", method, url, hasher.finalize())
+    }
+
+    fn cached(&self, key: &str) -> Option<Response> {
+        let cache = self.cache.as_ref()?;
+        let entries = cache.entries.lock().unwrap();
+        let (stored_at, response) = entries.get(key)?;
+        (stored_at.elapsed() < cache.ttl).then(|| response.clone())
+    }
+
+    fn store_cached(&self, key: String, response: &Response) {
+        if let Some(cache) = &self.cache {
+            cache.entries.lock().unwrap().insert(key, (Instant::now(), response.clone()));
+        }
+    }
+
+    fn is_retriable_status(status: u16) -> bool {
+        status == 429 || (500..600).contains(&status)
+    }
+
+    fn backoff_duration(policy: &RetryPolicy, attempt: u32) -> Duration {
+        let ceiling = policy.base_backoff_ms
+            .saturating_mul(1u64 << attempt.min(20))
+            .min(policy.max_backoff_ms);
+        let jittered = rand::thread_rng().gen_range(0..=ceiling.max(1));
+        Duration::from_millis(jittered)
+    }
+
+    fn retry_after(response: &RawResponse) -> Option<Duration> {
+        response.headers().get("retry-after")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|secs| secs.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+    }
+
+    // Sends the request built by `build`, retrying per `self.retry` on
+    // transport errors and 5xx/429 responses (honoring `Retry-After`).
+    // Runs on the client's runtime via `AsyncRNet::block_on`. The retry
+    // loop belongs to `RNet` only: `AsyncRNet`'s own methods send each
+    // request once, without retrying.
+    fn send_with_retry(&self, label: &str, build: impl Fn() -> RequestBuilder) -> TransRResult<Response> {
+        self.async_client.block_on(async {
+            let max_attempts = self.retry.as_ref().map(|p| p.max_retries + 1).unwrap_or(1);
+            let mut last_err = None;
+
+            for attempt in 0..max_attempts {
+                match build().send().await {
+                    Ok(raw) => {
+                        let status = raw.status().as_u16();
+
+                        if let Some(policy) = &self.retry {
+                            if Self::is_retriable_status(status) && attempt + 1 < max_attempts {
+                                let wait = Self::retry_after(&raw)
+                                    .unwrap_or_else(|| Self::backoff_duration(policy, attempt));
+                                Verbose::warn(&format!(
+                                    "{} got status {} — retrying in {:?} (attempt {}/{})",
+                                    label, status, wait, attempt + 1, max_attempts
+                                ));
+                                tokio::time::sleep(wait).await;
+                                last_err = Some(TransRError::NetworkError(
+                                    format!("{} failed with status {}", label, status)
+                                ));
+                                continue;
+                            }
+                        }
+
+                        return AsyncRNet::build_response(raw).await;
+                    }
+                    Err(e) => {
+                        let err = TransRError::NetworkError(format!("{} request failed: {}", label, e));
+
+                        if let Some(policy) = &self.retry {
+                            if attempt + 1 < max_attempts {
+                                let wait = Self::backoff_duration(policy, attempt);
+                                Verbose::warn(&format!(
+                                    "{} errored — retrying in {:?} (attempt {}/{})",
+                                    label, wait, attempt + 1, max_attempts
+                                ));
+                                tokio::time::sleep(wait).await;
+                                last_err = Some(err);
+                                continue;
+                            }
+                        }
+
+                        return Err(err);
+                    }
+                }
+            }
+
+            Err(last_err.unwrap_or_else(|| TransRError::NetworkError(format!("{} failed after retries", label))))
+        })
+    }
+
+    pub fn get(&self, url: &str) -> TransRResult<Response> {
+        let key = Self::cache_key("GET", url, None);
+        if let Some(cached) = self.cached(&key) {
+            Verbose::debug(&format!("Cache hit for GET {}", url));
+            return Ok(cached);
+        }
+
+        Verbose::info(&format!("GET request to: {}", url));
+        let client = &self.async_client.client;
+        let response = self.send_with_retry("GET", || client.get(url))?;
+        Verbose::debug(&format!("Response status: {}", response.status));
+
+        self.store_cached(key, &response);
+        Ok(response)
+    }
+
+    pub fn post(&self, url: &str, data: &str) -> TransRResult<Response> {
+        let key = Self::cache_key("POST", url, Some(data));
+        if let Some(cached) = self.cached(&key) {
+            Verbose::debug(&format!("Cache hit for POST {}", url));
+            return Ok(cached);
+        }
+
+        Verbose::info(&format!("POST request to: {}", url));
+        let client = &self.async_client.client;
+        let response = self.send_with_retry("POST", || {
+            client.post(url).header("Content-Type", "application/json").body(data.to_string())
+        })?;
+
+        self.store_cached(key, &response);
+        Ok(response)
+    }
+
+    pub fn put(&self, url: &str, data: &str) -> TransRResult<Response> {
+        let key = Self::cache_key("PUT", url, Some(data));
+        if let Some(cached) = self.cached(&key) {
+            Verbose::debug(&format!("Cache hit for PUT {}", url));
+            return Ok(cached);
+        }
+
+        Verbose::info(&format!("PUT request to: {}", url));
+        let client = &self.async_client.client;
+        let response = self.send_with_retry("PUT", || {
+            client.put(url).header("Content-Type", "application/json").body(data.to_string())
+        })?;
+
+        self.store_cached(key, &response);
+        Ok(response)
+    }
+
+    pub fn delete(&self, url: &str) -> TransRResult<Response> {
+        let key = Self::cache_key("DELETE", url, None);
+        if let Some(cached) = self.cached(&key) {
+            Verbose::debug(&format!("Cache hit for DELETE {}", url));
+            return Ok(cached);
+        }
+
+        Verbose::info(&format!("DELETE request to: {}", url));
+        let client = &self.async_client.client;
+        let response = self.send_with_retry("DELETE", || client.delete(url))?;
+
+        self.store_cached(key, &response);
+        Ok(response)
+    }
+
+    pub fn download(&self, url: &str, path: &str) -> TransRResult<()> {
+        self.async_client.block_on(self.async_client.download(url, path))
+    }
+
+    pub fn ping(&self, url: &str) -> TransRResult<bool> {
+        let client = &self.async_client.client;
+        self.async_client.block_on(async {
+            match client.head(url).timeout(Duration::from_secs(5)).send().await {
+                Ok(response) => Ok(response.status().is_success()),
+                Err(_) => Ok(false),
+            }
+        })
+    }
+}
+
+impl Default for RNet {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+// Crate-wide runtime shared by `AsyncRNet::new()` clients, so each one
+// doesn't spin up its own thread pool. `AsyncRNet::with_threads` opts a
+// client out into a dedicated runtime instead.
+static SHARED_RUNTIME: OnceLock<Arc<tokio::runtime::Runtime>> = OnceLock::new();
+
+fn shared_runtime() -> Arc<tokio::runtime::Runtime> {
+    SHARED_RUNTIME.get_or_init(|| {
+        Arc::new(
+            tokio::runtime::Runtime::new()
+                .expect("Failed to create shared Tokio runtime"),
+        )
+    }).clone()
+}
+
+// Async counterpart to `RNet`, built on `reqwest::Client`'s pooled,
+// non-blocking connections instead of blocking the calling thread per request.
+pub struct AsyncRNet {
+    client: reqwest::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AsyncRNet {
+    pub fn new() -> TransRResult<Self> {
+        Ok(AsyncRNet {
+            client: Self::build_client()?,
+            runtime: shared_runtime(),
+        })
+    }
+
+    // Builds a client on its own dedicated `threads`-worker runtime instead
+    // of the crate-wide shared one.
+    pub fn with_threads(threads: usize) -> TransRResult<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(threads.max(1))
+            .enable_all()
+            .build()
+            .map_err(|e| TransRError::NetworkError(format!("Failed to build runtime: {}", e)))?;
+
+        Ok(AsyncRNet {
+            client: Self::build_client()?,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    fn build_client() -> TransRResult<reqwest::Client> {
+        reqwest::Client::builder()
             .timeout(Duration::from_secs(30))
             .user_agent("TransR/1.0")
             .build()
-            .map_err(|e| TransRError::NetworkError(format!("Failed to create client: {}", e)))?;
-        
-        Ok(RNet { client })
+            .map_err(|e| TransRError::NetworkError(format!("Failed to create client: {}", e)))
     }
-    
-    pub fn get(&self, url: &str) -> TransRResult<Response> {
-        Verbose::info(&format!("GET request to: {}", url));
-        
-        let response = self.client.get(url)
-            .send()
-            .map_err(|e| TransRError::NetworkError(format!("GET request failed: {}", e)))?;
-        
+
+    async fn build_response(response: reqwest::Response) -> TransRResult<Response> {
         let status = response.status().as_u16();
         let url = response.url().to_string();
-        
+
         let mut headers = HashMap::new();
         for (key, value) in response.headers() {
             headers.insert(
@@ -45,138 +315,90 @@ impl RNet {
                 value.to_str().unwrap_or("").to_string(),
             );
         }
-        
-        let body = response.text()
+
+        let body = response.text().await
             .map_err(|e| TransRError::NetworkError(format!("Failed to read response body: {}", e)))?;
-        
-        Verbose::debug(&format!("Response status: {}", status));
-        
-        Ok(Response {
-            status,
-            body,
-            headers,
-            url,
-        })
+
+        Ok(Response { status, body, headers, url })
     }
-    
-    pub fn post(&self, url: &str, data: &str) -> TransRResult<Response> {
+
+    pub async fn get(&self, url: &str) -> TransRResult<Response> {
+        Verbose::info(&format!("GET request to: {}", url));
+
+        let response = self.client.get(url).send().await
+            .map_err(|e| TransRError::NetworkError(format!("GET request failed: {}", e)))?;
+
+        Self::build_response(response).await
+    }
+
+    pub async fn post(&self, url: &str, data: &str) -> TransRResult<Response> {
         Verbose::info(&format!("POST request to: {}", url));
-        
+
         let response = self.client.post(url)
             .header("Content-Type", "application/json")
             .body(data.to_string())
             .send()
+            .await
             .map_err(|e| TransRError::NetworkError(format!("POST request failed: {}", e)))?;
-        
-        let status = response.status().as_u16();
-        let url = response.url().to_string();
-        
-        let mut headers = HashMap::new();
-        for (key, value) in response.headers() {
-            headers.insert(
-                key.to_string(),
-                value.to_str().unwrap_or("").to_string(),
-            );
-        }
-        
-        let body = response.text()
-            .map_err(|e| TransRError::NetworkError(format!("Failed to read response body: {}", e)))?;
-        
-        Ok(Response {
-            status,
-            body,
-            headers,
-            url,
-        })
+
+        Self::build_response(response).await
     }
-    
-    pub fn put(&self, url: &str, data: &str) -> TransRResult<Response> {
+
+    pub async fn put(&self, url: &str, data: &str) -> TransRResult<Response> {
         Verbose::info(&format!("PUT request to: {}", url));
-        
+
         let response = self.client.put(url)
             .header("Content-Type", "application/json")
             .body(data.to_string())
             .send()
+            .await
             .map_err(|e| TransRError::NetworkError(format!("PUT request failed: {}", e)))?;
-        
-        let status = response.status().as_u16();
-        let url = response.url().to_string();
-        
-        let mut headers = HashMap::new();
-        for (key, value) in response.headers() {
-            headers.insert(
-                key.to_string(),
-                value.to_str().unwrap_or("").to_string(),
-            );
-        }
-        
-        let body = response.text()
-            .map_err(|e| TransRError::NetworkError(format!("Failed to read response body: {}", e)))?;
-        
-        Ok(Response {
-            status,
-            body,
-            headers,
-            url,
-        })
+
+        Self::build_response(response).await
     }
-    
-    pub fn delete(&self, url: &str) -> TransRResult<Response> {
+
+    pub async fn delete(&self, url: &str) -> TransRResult<Response> {
         Verbose::info(&format!("DELETE request to: {}", url));
-        
-        let response = self.client.delete(url)
-            .send()
+
+        let response = self.client.delete(url).send().await
             .map_err(|e| TransRError::NetworkError(format!("DELETE request failed: {}", e)))?;
-        
-        let status = response.status().as_u16();
-        let url = response.url().to_string();
-        
-        let mut headers = HashMap::new();
-        for (key, value) in response.headers() {
-            headers.insert(
-                key.to_string(),
-                value.to_str().unwrap_or("").to_string(),
-            );
-        }
-        
-        let body = response.text()
-            .map_err(|e| TransRError::NetworkError(format!("Failed to read response body: {}", e)))?;
-        
-        Ok(Response {
-            status,
-            body,
-            headers,
-            url,
-        })
+
+        Self::build_response(response).await
     }
-    
-    pub fn download(&self, url: &str, path: &str) -> TransRResult<()> {
+
+    pub async fn download(&self, url: &str, path: &str) -> TransRResult<()> {
         Verbose::info(&format!("Downloading from {} to {}", url, path));
-        
-        let response = self.client.get(url)
-            .send()
+
+        let response = self.client.get(url).send().await
             .map_err(|e| TransRError::NetworkError(format!("Download failed: {}", e)))?;
-        
-        let content = response.bytes()
+
+        let content = response.bytes().await
             .map_err(|e| TransRError::NetworkError(format!("Failed to read content: {}", e)))?;
-        
-        std::fs::write(path, content)
-            .map_err(|e| TransRError::IoError(e))?;
-        
+
+        std::fs::write(path, content).map_err(TransRError::IoError)?;
+
         Verbose::success(&format!("Downloaded to {}", path));
         Ok(())
     }
-    
-    pub fn ping(&self, url: &str) -> TransRResult<bool> {
-        match self.client.head(url).timeout(Duration::from_secs(5)).send() {
-            Ok(response) => Ok(response.status().is_success()),
-            Err(_) => Ok(false),
-        }
+
+    // Runs a GET for every url concurrently, returning one result per url
+    // in the same order.
+    pub async fn get_all(&self, urls: &[String]) -> Vec<TransRResult<Response>> {
+        futures::future::join_all(urls.iter().map(|url| self.get(url))).await
     }
-}
 
-impl Default for RNet {
-    fn default() -> Self {
-        Self::new().unwrap()
+    // Runs `future` to completion on this client's runtime, so sync callers
+    // (like `RNet`) can drive the async implementation without an `await`.
+    pub fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    // Shuts this client's runtime down, waiting up to 30s for in-flight
+    // requests to finish. A no-op for clients sharing the crate-wide
+    // runtime, since other clients may still depend on it.
+    pub fn shutdown(self) {
+        if let Ok(runtime) = Arc::try_unwrap(self.runtime) {
+            runtime.shutdown_timeout(Duration::from_secs(30));
+        }
     }
 }
@@ -41,6 +41,14 @@ impl TimerU {
     pub fn reset(&mut self) {
         self.start_time = Some(Instant::now());
     }
+
+    // Runs `f`, timing it with `Instant`, and returns its result alongside
+    // the elapsed duration — no mutable timer instance required.
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, std::time::Duration) {
+        let start = Instant::now();
+        let result = f();
+        (result, start.elapsed())
+    }
 }
 
 pub fn now_local() -> DateTime<Local> {
@@ -65,6 +73,13 @@ pub fn timestamp_ms() -> i64 {
         .as_millis() as i64
 }
 
+pub fn timestamp_us() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_micros() as i64
+}
+
 pub fn from_timestamp(ts: i64) -> TransRResult<DateTime<Utc>> {
     Utc.timestamp_opt(ts, 0)
         .single()
@@ -126,6 +141,17 @@ impl Benchmark {
         println!("⏱️  [BENCHMARK] {} took {:?}", self.name, duration);
         duration
     }
+
+    // Times `f`, prints the same benchmark line `finish()` does, and
+    // returns both the closure's result and the elapsed duration — so
+    // pipeline code can wrap any operation without juggling a mutable timer.
+    pub fn span<T>(name: &str, f: impl FnOnce() -> T) -> (T, std::time::Duration) {
+        let start = Instant::now();
+        let result = f();
+        let duration = start.elapsed();
+        println!("⏱️  [BENCHMARK] {} took {:?}", name, duration);
+        (result, duration)
+    }
 }
 
 impl Default for TimerU {
@@ -15,6 +15,10 @@ mod plugins {
     pub mod decorator;
     pub mod rustnetx;
     pub mod hyros;
+    pub mod ipguard;
+    pub mod worker;
+    #[cfg(feature = "docker")]
+    pub mod dockerscan;
 }
 
 use pyo3::prelude::*;
@@ -59,13 +63,19 @@ impl PipelineR {
         self.pipeline.math(operation, args);
     }
     
-    fn http_get(&mut self, url: String) {
-        self.pipeline.http_get(url);
+    #[pyo3(signature = (url, max_retries=None, cache_ttl_secs=None))]
+    fn http_get(&mut self, url: String, max_retries: Option<u32>, cache_ttl_secs: Option<u64>) {
+        self.pipeline.http_get_with_policy(url, max_retries, cache_ttl_secs);
     }
     
     fn hash(&mut self, algorithm: String, data: String) {
         self.pipeline.hash(algorithm, data);
     }
+
+    // Builds a Merkle tree over `leaves` and queues a task resolving to its root digest.
+    fn merkle(&mut self, leaves: Vec<String>, algorithm: String) {
+        self.pipeline.merkle(leaves, algorithm);
+    }
     
     fn loop_calc(&mut self, iterations: u32) {
         self.pipeline.heavy_loop(iterations);
@@ -74,8 +84,15 @@ impl PipelineR {
     fn blockchain(&mut self, height: u32, weight: u32) {
         self.pipeline.blockchain_loop(height, weight);
     }
+
+    // Mines a real proof-of-work block: searches for a nonce such that
+    // `keccak256(header || nonce)` has `difficulty` leading zero bits.
+    fn mine(&mut self, header: String, difficulty: u32) {
+        self.pipeline.mine(header, difficulty);
+    }
     
-    fn scan_ports(&mut self, host: String, ports: Vec<u16>) {
+    fn scan_ports(&mut self, host: String, ports: Vec<u16>, progress: Option<PyObject>) {
+        self.pipeline.set_scan_progress(progress_callback(progress));
         self.pipeline.port_scan(host, ports);
     }
     
@@ -87,6 +104,42 @@ impl PipelineR {
         self.pipeline.run()
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
     }
+
+    // Runs queued tasks across up to `max_concurrency` worker threads instead
+    // of sequentially — useful when most tasks are independent I/O (port
+    // scans, HTTP requests, vuln scans).
+    fn run_parallel(&mut self, max_concurrency: usize) -> PyResult<()> {
+        self.pipeline.run_parallel(max_concurrency)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
+    // Schedules a recurring port scan, e.g. to re-scan a host every 5 minutes and diff results.
+    fn schedule_port_scan(&mut self, host: String, ports: Vec<u16>, interval_secs: u64) {
+        self.pipeline.schedule(
+            transr::Task::PortScan { host, ports },
+            std::time::Duration::from_secs(interval_secs),
+        );
+    }
+
+    // Schedules a recurring HTTP GET.
+    fn schedule_http_get(&mut self, url: String, interval_secs: u64) {
+        self.pipeline.schedule(
+            transr::Task::NetworkRequest {
+                method: "GET".to_string(),
+                url,
+                data: None,
+                max_retries: None,
+                cache_ttl_secs: None,
+            },
+            std::time::Duration::from_secs(interval_secs),
+        );
+    }
+
+    #[pyo3(signature = (max_iterations=None))]
+    fn run_forever(&mut self, max_iterations: Option<u32>) -> PyResult<()> {
+        self.pipeline.run_forever(max_iterations)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
     
     fn clear(&mut self) {
         self.pipeline.clear();
@@ -109,6 +162,27 @@ impl PipelineR {
     }
 }
 
+// Wraps a Python callable as a `plugins::rustnetx::ProgressCallback`, re-acquiring
+// the GIL for each invocation since scanning runs with the GIL released.
+fn progress_callback(callback: Option<PyObject>) -> Option<plugins::rustnetx::ProgressCallback> {
+    callback.map(|callback| {
+        std::sync::Arc::new(move |progress: plugins::rustnetx::ScanProgress| {
+            Python::with_gil(|py| {
+                let dict = PyDict::new(py);
+                let _ = dict.set_item("scanned", progress.scanned);
+                let _ = dict.set_item("total", progress.total);
+                let _ = dict.set_item("host", progress.host);
+                let _ = dict.set_item("last_port", progress.last_port);
+                let _ = dict.set_item("open", progress.open);
+
+                if let Err(e) = callback.call1(py, (dict,)) {
+                    e.print(py);
+                }
+            });
+        }) as plugins::rustnetx::ProgressCallback
+    })
+}
+
 // ==================== Feature Module ====================
 #[pyclass]
 struct Feature;
@@ -236,12 +310,20 @@ impl Feature {
     
     // Network features
     #[pyo3(name = "http_get")]
-    fn http_get(&self, py: Python, url: String) -> PyResult<PyObject> {
-        use func::rnet::RNet;
-        
-        let client = RNet::new()
+    #[pyo3(signature = (url, max_retries=None, cache_ttl_secs=None))]
+    fn http_get(&self, py: Python, url: String, max_retries: Option<u32>, cache_ttl_secs: Option<u64>) -> PyResult<PyObject> {
+        use func::rnet::{RNet, RetryPolicy};
+
+        let mut client = RNet::new()
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-        
+
+        if let Some(max_retries) = max_retries {
+            client = client.with_retry(RetryPolicy { max_retries, ..RetryPolicy::default() });
+        }
+        if let Some(ttl) = cache_ttl_secs {
+            client = client.with_cache(std::time::Duration::from_secs(ttl));
+        }
+
         let response = client.get(&url)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
         
@@ -271,37 +353,87 @@ impl Feature {
     
     // Scanner features
     #[pyo3(name = "scan_port")]
-    fn scan_port(&self, py: Python, host: String, port: u16) -> PyResult<PyObject> {
-        use plugins::rustnetx::RustNetX;
-        
+    fn scan_port(&self, py: Python, host: String, port: u16, progress: Option<PyObject>) -> PyResult<PyObject> {
+        use plugins::rustnetx::{RustNetX, ScanProgress};
+
         let scanner = RustNetX::new();
-        let result = scanner.scan_port(&host, port);
-        
+        let callback = progress_callback(progress);
+
+        // Release the GIL for the scan itself so other Python threads aren't
+        // blocked on it; `callback` re-acquires the GIL per invocation.
+        let result = py.allow_threads(|| {
+            let result = scanner.scan_port(&host, port);
+
+            if let Some(callback) = &callback {
+                callback(ScanProgress {
+                    scanned: 1,
+                    total: 1,
+                    host: host.clone(),
+                    last_port: port,
+                    open: result.is_open,
+                });
+            }
+
+            result
+        });
+
         let dict = PyDict::new(py);
         dict.set_item("host", result.host)?;
         dict.set_item("port", result.port)?;
         dict.set_item("is_open", result.is_open)?;
         dict.set_item("service", result.service)?;
-        
+
         Ok(dict.to_object(py))
     }
-    
+
     #[pyo3(name = "quick_scan")]
-    fn quick_scan(&self, py: Python, host: String) -> PyResult<PyObject> {
+    fn quick_scan(&self, py: Python, host: String, progress: Option<PyObject>) -> PyResult<PyObject> {
         use plugins::rustnetx::RustNetX;
-        
+
         let scanner = RustNetX::new();
-        let result = scanner.quick_scan(&host);
-        
+        let callback = progress_callback(progress);
+
+        // Release the GIL for the scan itself so other Python threads aren't
+        // blocked on it; `callback` re-acquires the GIL per invocation.
+        let result = py.allow_threads(|| match callback {
+            Some(callback) => scanner.quick_scan_with_progress(&host, callback),
+            None => scanner.quick_scan(&host),
+        });
+
         let dict = PyDict::new(py);
         dict.set_item("host", result.host)?;
         dict.set_item("is_alive", result.is_alive)?;
         dict.set_item("open_ports", result.open_ports)?;
         dict.set_item("services", result.services)?;
-        
+
         Ok(dict.to_object(py))
     }
     
+    // Docker features
+    #[cfg(feature = "docker")]
+    #[pyo3(name = "scan_docker")]
+    fn scan_docker(&self, py: Python) -> PyResult<PyObject> {
+        use plugins::dockerscan::DockerScan;
+
+        let scanner = DockerScan::new();
+        let reports = scanner.scan_all()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        let py_list = PyList::empty(py);
+        for report in reports {
+            let dict = PyDict::new(py);
+            dict.set_item("container", report.container)?;
+            dict.set_item("image", report.image)?;
+            dict.set_item("ip", report.ip)?;
+            dict.set_item("open_ports", report.open_ports)?;
+            dict.set_item("services", report.services)?;
+            dict.set_item("banners", report.banners)?;
+            py_list.append(dict)?;
+        }
+
+        Ok(py_list.to_object(py))
+    }
+
     #[pyo3(name = "vuln_scan")]
     fn vuln_scan(&self, py: Python, url: String) -> PyResult<PyObject> {
         use plugins::hyros::HyrOS;
@@ -328,12 +460,147 @@ impl Feature {
     }
 }
 
+// ==================== Guardian (IpGuard) Class ====================
+#[pyclass]
+struct Guardian {
+    inner: std::sync::Arc<plugins::ipguard::IpGuard>,
+}
+
+#[pymethods]
+impl Guardian {
+    #[new]
+    #[pyo3(signature = (max_failures=5, window_secs=60, ban_time_secs=3600))]
+    fn new(max_failures: usize, window_secs: u64, ban_time_secs: u64) -> Self {
+        Guardian {
+            inner: std::sync::Arc::new(plugins::ipguard::IpGuard::new(max_failures, window_secs, ban_time_secs)),
+        }
+    }
+
+    fn ban_ip(&self, ip: String) -> PyResult<()> {
+        let addr: std::net::IpAddr = ip.parse()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid IP address: {}", e)))?;
+
+        self.inner.ban_ip(addr)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
+    fn unban_ip(&self, ip: String) -> PyResult<()> {
+        let addr: std::net::IpAddr = ip.parse()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid IP address: {}", e)))?;
+
+        self.inner.unban_ip(addr)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
+    fn list_banned(&self, py: Python) -> PyResult<PyObject> {
+        let py_list = PyList::empty(py);
+        let now = std::time::Instant::now();
+
+        for banned in self.inner.list_banned() {
+            let dict = PyDict::new(py);
+            dict.set_item("ip", banned.ip.to_string())?;
+            dict.set_item("expires_in_secs", banned.expires_at.saturating_duration_since(now).as_secs())?;
+            py_list.append(dict)?;
+        }
+
+        Ok(py_list.to_object(py))
+    }
+
+    // `rules` is a list of (regex_pattern, ip_capture_group) pairs; matching
+    // lines feed straight into the sliding-window ban logic in the background.
+    fn watch(&self, paths: Vec<String>, rules: Vec<(String, usize)>) -> PyResult<()> {
+        use regex::Regex;
+
+        let compiled = rules.into_iter()
+            .map(|(pattern, ip_group)| {
+                Regex::new(&pattern)
+                    .map(|pattern| plugins::ipguard::BanRule { pattern, ip_group })
+                    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid rule regex: {}", e)))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        self.inner.watch(paths, compiled);
+        Ok(())
+    }
+}
+
+// ==================== Worker (WorkerManager) Class ====================
+
+// Adapts a Python callable to `plugins::worker::Worker`, re-acquiring the GIL
+// for each invocation since the schedule loop runs the callback off-thread.
+struct PyWorker {
+    callback: PyObject,
+}
+
+impl plugins::worker::Worker for PyWorker {
+    fn work(&mut self) -> errhuman::TransRResult<plugins::worker::WorkerState> {
+        Python::with_gil(|py| match self.callback.call0(py) {
+            Ok(_) => Ok(plugins::worker::WorkerState::Idle),
+            Err(e) => Err(errhuman::TransRError::ValidationError(format!("Python worker errored: {}", e))),
+        })
+    }
+}
+
+#[pyclass]
+struct WorkerR {
+    inner: plugins::worker::WorkerManager,
+}
+
+#[pymethods]
+impl WorkerR {
+    #[new]
+    fn new() -> Self {
+        WorkerR { inner: plugins::worker::WorkerManager::new() }
+    }
+
+    // Registers `callback` to run every `interval_secs`. Nothing runs until
+    // `start()` launches the schedule loop.
+    fn register(&mut self, name: String, callback: PyObject, interval_secs: u64) {
+        self.inner.register(&name, PyWorker { callback }, std::time::Duration::from_secs(interval_secs));
+    }
+
+    fn start(&mut self) {
+        self.inner.start();
+    }
+
+    fn pause(&self, name: String) -> PyResult<()> {
+        self.inner.control(&name, plugins::worker::ControlMessage::Pause)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
+    fn resume(&self, name: String) -> PyResult<()> {
+        self.inner.control(&name, plugins::worker::ControlMessage::Resume)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
+    fn cancel(&self, name: String) -> PyResult<()> {
+        self.inner.control(&name, plugins::worker::ControlMessage::Cancel)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+
+    fn list(&self, py: Python) -> PyResult<PyObject> {
+        let py_list = PyList::empty(py);
+
+        for info in self.inner.list() {
+            let dict = PyDict::new(py);
+            dict.set_item("name", info.name)?;
+            dict.set_item("state", format!("{:?}", info.state))?;
+            dict.set_item("error_count", info.error_count)?;
+            py_list.append(dict)?;
+        }
+
+        Ok(py_list.to_object(py))
+    }
+}
+
 // ==================== Module Definition ====================
 #[pymodule]
 fn transR(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PipelineR>()?;
     m.add_class::<Feature>()?;
-    
+    m.add_class::<Guardian>()?;
+    m.add_class::<WorkerR>()?;
+
     // Add version
     m.add("__version__", "0.1.0")?;
     
@@ -1,15 +1,66 @@
 use crate::errhuman::{TransRError, TransRResult};
 use crate::vvv::Verbose;
+use chrono::NaiveDate;
+use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 type DecoratorFn = Arc<dyn Fn(&mut TaskContext) -> TransRResult<()> + Send + Sync>;
 
+// How urgently a journaled task should be worked; `TaskStore::list()` sorts
+// highest priority first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Priority {
+    High,
+    Medium,
+    Low,
+}
+
+// Time logged against a task on a given day. Minutes are normalized on
+// construction so `minutes` never reaches 60 (it carries into `hours`).
+//
+// `logged_date` is stored as an ISO-8601 (`YYYY-MM-DD`) string rather than
+// `chrono::NaiveDate` directly: chrono only implements `Serialize`/
+// `Deserialize` when its `serde` Cargo feature is enabled, which nothing
+// else in this crate turns on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub logged_date: String,
+    pub hours: u64,
+    pub minutes: u64,
+}
+
+impl TimeEntry {
+    fn new(logged_date: NaiveDate, duration: Duration) -> Self {
+        let total_minutes = duration.as_secs() / 60;
+        TimeEntry {
+            logged_date: logged_date.to_string(),
+            hours: total_minutes / 60,
+            minutes: total_minutes % 60,
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs((self.hours * 60 + self.minutes) * 60)
+    }
+
+    pub fn logged_date(&self) -> TransRResult<NaiveDate> {
+        NaiveDate::parse_from_str(&self.logged_date, "%Y-%m-%d")
+            .map_err(|e| TransRError::ParseError(format!("Invalid logged_date '{}': {}", self.logged_date, e)))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskContext {
     pub name: String,
     pub args: HashMap<String, String>,
     pub result: Option<String>,
     pub metadata: HashMap<String, String>,
+    pub priority: Priority,
+    pub time_entries: Vec<TimeEntry>,
 }
 
 impl TaskContext {
@@ -19,10 +70,132 @@ impl TaskContext {
             args: HashMap::new(),
             result: None,
             metadata: HashMap::new(),
+            priority: Priority::Medium,
+            time_entries: Vec::new(),
         }
     }
 }
 
+// Stable numeric id a `TaskContext` is assigned once it's written into a
+// `TaskStore`.
+pub type Id = u64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRecord {
+    pub id: Id,
+    pub context: TaskContext,
+}
+
+// Persists decorated tasks as one JSON file per task, so a `TaskContext`
+// survives past the process that created it. Writes are crash-safe: each
+// update is written to a temp file, fsynced, then renamed over the target.
+pub struct TaskStore {
+    dir: PathBuf,
+    next_id: Mutex<Id>,
+}
+
+impl TaskStore {
+    pub fn new(dir: impl Into<PathBuf>) -> TransRResult<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir).map_err(TransRError::IoError)?;
+        let next_id = Self::scan_next_id(&dir)?;
+
+        Ok(TaskStore { dir, next_id: Mutex::new(next_id) })
+    }
+
+    fn scan_next_id(dir: &Path) -> TransRResult<Id> {
+        let mut max_id = 0;
+
+        for entry in std::fs::read_dir(dir).map_err(TransRError::IoError)? {
+            let entry = entry.map_err(TransRError::IoError)?;
+            if let Some(id) = Self::id_from_path(&entry.path()) {
+                max_id = max_id.max(id);
+            }
+        }
+
+        Ok(max_id + 1)
+    }
+
+    fn id_from_path(path: &Path) -> Option<Id> {
+        if path.extension()? != "json" {
+            return None;
+        }
+        path.file_stem()?.to_str()?.parse::<Id>().ok()
+    }
+
+    fn path_for(&self, id: Id) -> PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    fn write_record(&self, record: &TaskRecord) -> TransRResult<()> {
+        let target = self.path_for(record.id);
+        let tmp_path = self.dir.join(format!(".{}.json.tmp", record.id));
+
+        let json = serde_json::to_string_pretty(record)
+            .map_err(|e| TransRError::ParseError(format!("Failed to serialize task {}: {}", record.id, e)))?;
+
+        let mut file = std::fs::File::create(&tmp_path).map_err(TransRError::IoError)?;
+        file.write_all(json.as_bytes()).map_err(TransRError::IoError)?;
+        file.sync_all().map_err(TransRError::IoError)?;
+
+        std::fs::rename(&tmp_path, &target).map_err(TransRError::IoError)?;
+        Ok(())
+    }
+
+    fn read_record(&self, id: Id) -> TransRResult<TaskRecord> {
+        let data = std::fs::read_to_string(self.path_for(id)).map_err(TransRError::IoError)?;
+        serde_json::from_str(&data)
+            .map_err(|e| TransRError::ParseError(format!("Failed to parse task {}: {}", id, e)))
+    }
+
+    // Journals a new task under `name` and returns its stable id.
+    pub fn create(&self, name: &str, priority: Priority) -> TransRResult<Id> {
+        let mut context = TaskContext::new(name);
+        context.priority = priority;
+        self.save(context)
+    }
+
+    // Journals an already-built `TaskContext` (e.g. one `DecoratorRegistry`
+    // has already applied decorators to) under a fresh id.
+    pub fn save(&self, context: TaskContext) -> TransRResult<Id> {
+        let mut next_id = self.next_id.lock().unwrap();
+        let id = *next_id;
+        *next_id += 1;
+
+        Verbose::debug(&format!("Journaled task #{}: {}", id, context.name));
+        self.write_record(&TaskRecord { id, context })?;
+        Ok(id)
+    }
+
+    // Every journaled task, sorted by priority (`High` first) then id.
+    pub fn list(&self) -> TransRResult<Vec<TaskRecord>> {
+        let mut records = Vec::new();
+
+        for entry in std::fs::read_dir(&self.dir).map_err(TransRError::IoError)? {
+            let entry = entry.map_err(TransRError::IoError)?;
+            if let Some(id) = Self::id_from_path(&entry.path()) {
+                records.push(self.read_record(id)?);
+            }
+        }
+
+        records.sort_by(|a, b| a.context.priority.cmp(&b.context.priority).then(a.id.cmp(&b.id)));
+        Ok(records)
+    }
+
+    // Appends a time entry for `id`, normalizing minutes into hours.
+    pub fn log_time(&self, id: Id, date: NaiveDate, duration: Duration) -> TransRResult<()> {
+        let mut record = self.read_record(id)?;
+        record.context.time_entries.push(TimeEntry::new(date, duration));
+        self.write_record(&record)
+    }
+
+    // Sum of all time logged against `id`.
+    pub fn total_time(&self, id: Id) -> TransRResult<Duration> {
+        let record = self.read_record(id)?;
+        Ok(record.context.time_entries.iter().map(TimeEntry::duration).sum())
+    }
+}
+
 pub struct DecoratorRegistry {
     decorators: Arc<Mutex<HashMap<String, DecoratorFn>>>,
 }
@@ -152,14 +325,16 @@ impl Default for DecoratorRegistry {
     }
 }
 
-// Helper function to create a task with decorators
-pub fn decorated_task(name: &str, decorators: Vec<&str>) -> TransRResult<TaskContext> {
+// Helper function to create a task with decorators, journaling it into `store`
+// so the resulting `TaskContext` survives past this process.
+pub fn decorated_task(store: &TaskStore, name: &str, decorators: Vec<&str>) -> TransRResult<TaskRecord> {
     let registry = DecoratorRegistry::new();
     let mut ctx = TaskContext::new(name);
-    
+
     for decorator in decorators {
         registry.apply(decorator, &mut ctx)?;
     }
-    
-    Ok(ctx)
+
+    let id = store.save(ctx.clone())?;
+    Ok(TaskRecord { id, context: ctx })
 }
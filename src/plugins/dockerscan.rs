@@ -0,0 +1,211 @@
+use crate::errhuman::{TransRError, TransRResult};
+use crate::vvv::Verbose;
+use crate::plugins::rustnetx::RustNetX;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::os::unix::net::UnixStream;
+
+const DOCKER_SOCK: &str = "/var/run/docker.sock";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerReport {
+    pub container: String,
+    pub image: String,
+    pub ip: String,
+    pub open_ports: Vec<u16>,
+    pub services: Vec<String>,
+    pub banners: Vec<String>,
+}
+
+// Talks to the Docker Engine API over `/var/run/docker.sock` and feeds
+// discovered container IPs/ports straight into `RustNetX`.
+pub struct DockerScan;
+
+impl DockerScan {
+    pub fn new() -> Self {
+        DockerScan
+    }
+
+    fn request(&self, path: &str) -> TransRResult<Value> {
+        let stream = UnixStream::connect(DOCKER_SOCK).map_err(TransRError::IoError)?;
+        let mut writer = stream.try_clone().map_err(TransRError::IoError)?;
+        let mut reader = BufReader::new(stream);
+
+        let request = format!("GET {} HTTP/1.1\r\nHost: docker\r\nConnection: close\r\n\r\n", path);
+        writer.write_all(request.as_bytes()).map_err(TransRError::IoError)?;
+
+        let body = read_http_body(&mut reader)?;
+
+        serde_json::from_slice(&body)
+            .map_err(|e| TransRError::NetworkError(format!("Failed to parse Docker API response: {}", e)))
+    }
+
+    pub fn list_containers(&self) -> TransRResult<Vec<Value>> {
+        Verbose::info("🐳 Listing running Docker containers");
+
+        self.request("/containers/json")?
+            .as_array()
+            .cloned()
+            .ok_or_else(|| TransRError::NetworkError("Expected a JSON array of containers".to_string()))
+    }
+
+    pub fn inspect(&self, container_id: &str) -> TransRResult<Value> {
+        self.request(&format!("/containers/{}/json", container_id))
+    }
+
+    // Enumerates running containers and scans each one's exposed ports,
+    // returning a report per container with its image, IP, open ports,
+    // identified services, and grabbed banners.
+    pub fn scan_all(&self) -> TransRResult<Vec<ContainerReport>> {
+        let containers = self.list_containers()?;
+        let scanner = RustNetX::new();
+        let mut reports = Vec::new();
+
+        for container in containers {
+            let id = container["Id"].as_str().unwrap_or_default();
+            let image = container["Image"].as_str().unwrap_or("unknown").to_string();
+            let name = container["Names"].as_array()
+                .and_then(|names| names.first())
+                .and_then(|n| n.as_str())
+                .unwrap_or(id)
+                .trim_start_matches('/')
+                .to_string();
+
+            let detail = match self.inspect(id) {
+                Ok(detail) => detail,
+                Err(e) => {
+                    Verbose::warn(&format!("Skipping container {}: {}", name, e));
+                    continue;
+                }
+            };
+
+            let ip = detail["NetworkSettings"]["IPAddress"].as_str().unwrap_or("").to_string();
+            if ip.is_empty() {
+                continue;
+            }
+
+            let ports: Vec<u16> = detail["NetworkSettings"]["Ports"].as_object()
+                .map(|ports| {
+                    ports.keys()
+                        .filter_map(|key| key.split('/').next())
+                        .filter_map(|port| port.parse::<u16>().ok())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let scan_results = scanner.scan_ports(&ip, ports);
+            let open_ports: Vec<u16> = scan_results.iter()
+                .filter(|r| r.is_open)
+                .map(|r| r.port)
+                .collect();
+
+            let services: Vec<String> = scan_results.iter()
+                .filter(|r| r.is_open && r.service.is_some())
+                .map(|r| format!("{}: {}", r.port, r.service.as_ref().unwrap()))
+                .collect();
+
+            let banners: Vec<String> = open_ports.iter()
+                .filter_map(|&port| scanner.banner_grab(&ip, port).ok())
+                .collect();
+
+            reports.push(ContainerReport {
+                container: name,
+                image,
+                ip,
+                open_ports,
+                services,
+                banners,
+            });
+        }
+
+        Verbose::success(&format!("✓ Scanned {} Docker containers", reports.len()));
+        Ok(reports)
+    }
+}
+
+impl Default for DockerScan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Minimal HTTP/1.1 response reader: checks the status line, then decodes
+// the body per `Content-Length` or `Transfer-Encoding: chunked` (dockerd
+// uses chunked framing for most of its API responses).
+fn read_http_body(reader: &mut BufReader<UnixStream>) -> TransRResult<Vec<u8>> {
+    let status_line = read_http_line(reader)?;
+    let status = status_line.split_whitespace().nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| TransRError::NetworkError(format!("Malformed Docker API status line: {}", status_line)))?;
+
+    if !(200..300).contains(&status) {
+        return Err(TransRError::NetworkError(format!("Docker API returned HTTP {}", status)));
+    }
+
+    let mut content_length = None;
+    let mut chunked = false;
+
+    loop {
+        let line = read_http_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse::<usize>().ok(),
+                "transfer-encoding" => chunked = value.trim().eq_ignore_ascii_case("chunked"),
+                _ => {}
+            }
+        }
+    }
+
+    if chunked {
+        read_chunked_body(reader)
+    } else if let Some(len) = content_length {
+        let mut body = vec![0u8; len];
+        reader.read_exact(&mut body).map_err(TransRError::IoError)?;
+        Ok(body)
+    } else {
+        // Neither header present: `Connection: close` means the body runs to EOF.
+        let mut body = Vec::new();
+        reader.read_to_end(&mut body).map_err(TransRError::IoError)?;
+        Ok(body)
+    }
+}
+
+fn read_http_line(reader: &mut BufReader<UnixStream>) -> TransRResult<String> {
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(TransRError::IoError)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn read_chunked_body(reader: &mut BufReader<UnixStream>) -> TransRResult<Vec<u8>> {
+    let mut body = Vec::new();
+
+    loop {
+        let size_line = read_http_line(reader)?;
+        let size = usize::from_str_radix(size_line.split(';').next().unwrap_or("").trim(), 16)
+            .map_err(|e| TransRError::NetworkError(format!("Malformed chunk size '{}': {}", size_line, e)))?;
+
+        if size == 0 {
+            // Trailing headers (if any), terminated by a blank line.
+            loop {
+                if read_http_line(reader)?.is_empty() {
+                    break;
+                }
+            }
+            break;
+        }
+
+        let mut chunk = vec![0u8; size];
+        reader.read_exact(&mut chunk).map_err(TransRError::IoError)?;
+        body.extend_from_slice(&chunk);
+
+        // Each chunk is followed by a trailing CRLF.
+        read_http_line(reader)?;
+    }
+
+    Ok(body)
+}
@@ -0,0 +1,218 @@
+use crate::errhuman::{TransRError, TransRResult};
+use crate::vvv::Verbose;
+use crate::debug_log;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::net::IpAddr;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use regex::Regex;
+
+// A single detection rule: a regex run against every new log line, plus the
+// capture group index that holds the offending IP address.
+#[derive(Debug, Clone)]
+pub struct BanRule {
+    pub pattern: Regex,
+    pub ip_group: usize,
+}
+
+pub struct BannedIp {
+    pub ip: IpAddr,
+    pub expires_at: Instant,
+}
+
+// Log-tailing intrusion-detection engine: watches log files for matching
+// failure lines, tracks a sliding per-IP failure window, and bans/unbans
+// offenders via the host firewall.
+pub struct IpGuard {
+    max_failures: usize,
+    window: Duration,
+    ban_time: Duration,
+    failures: Mutex<HashMap<IpAddr, VecDeque<Instant>>>,
+    banned: Mutex<HashMap<IpAddr, Instant>>,
+}
+
+impl IpGuard {
+    pub fn new(max_failures: usize, window_secs: u64, ban_time_secs: u64) -> Self {
+        IpGuard {
+            max_failures,
+            window: Duration::from_secs(window_secs),
+            ban_time: Duration::from_secs(ban_time_secs),
+            failures: Mutex::new(HashMap::new()),
+            banned: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Records a failure for `ip`, pruning entries older than `window`, and
+    // bans the IP once it crosses `max_failures` within the window. Returns
+    // whether this call triggered a ban.
+    pub fn record_failure(&self, ip: IpAddr) -> TransRResult<bool> {
+        let now = Instant::now();
+        let should_ban = {
+            let mut failures = self.failures.lock().unwrap();
+            let window = self.window;
+            let entry = failures.entry(ip).or_insert_with(VecDeque::new);
+
+            entry.push_back(now);
+            while matches!(entry.front(), Some(&ts) if now.duration_since(ts) > window) {
+                entry.pop_front();
+            }
+
+            entry.len() >= self.max_failures
+        };
+
+        if should_ban {
+            self.failures.lock().unwrap().remove(&ip);
+            self.ban_ip(ip)?;
+        }
+
+        Ok(should_ban)
+    }
+
+    pub fn ban_ip(&self, ip: IpAddr) -> TransRResult<()> {
+        if self.banned.lock().unwrap().contains_key(&ip) {
+            return Ok(());
+        }
+
+        Verbose::warn(&format!("🚫 [IpGuard] Banning {} for {}s", ip, self.ban_time.as_secs()));
+        apply_firewall_ban(ip)?;
+
+        self.banned.lock().unwrap().insert(ip, Instant::now() + self.ban_time);
+        Ok(())
+    }
+
+    pub fn unban_ip(&self, ip: IpAddr) -> TransRResult<()> {
+        Verbose::info(&format!("✓ [IpGuard] Unbanning {}", ip));
+        remove_firewall_ban(ip)?;
+
+        self.banned.lock().unwrap().remove(&ip);
+        Ok(())
+    }
+
+    pub fn list_banned(&self) -> Vec<BannedIp> {
+        self.banned.lock().unwrap()
+            .iter()
+            .map(|(&ip, &expires_at)| BannedIp { ip, expires_at })
+            .collect()
+    }
+
+    // Auto-unbans anything past its `ban_time`. Called periodically by the watch loop.
+    fn sweep_expired(&self) -> TransRResult<()> {
+        let now = Instant::now();
+        let expired: Vec<IpAddr> = self.banned.lock().unwrap()
+            .iter()
+            .filter(|(_, &expires_at)| expires_at <= now)
+            .map(|(&ip, _)| ip)
+            .collect();
+
+        for ip in expired {
+            self.unban_ip(ip)?;
+        }
+        Ok(())
+    }
+
+    // Spawns one tailing thread per path, matching `rules` against every new
+    // line, and feeding hits into the sliding-window ban logic. Returns once
+    // the watcher threads are spawned; they keep running in the background.
+    pub fn watch(self: &Arc<Self>, paths: Vec<String>, rules: Vec<BanRule>) {
+        let rules = Arc::new(rules);
+
+        for path in paths {
+            let guard = Arc::clone(self);
+            let rules = Arc::clone(&rules);
+
+            thread::spawn(move || {
+                if let Err(e) = tail_and_match(&path, &rules, &guard) {
+                    Verbose::error(&format!("[IpGuard] stopped watching {}: {}", path, e));
+                }
+            });
+        }
+    }
+}
+
+// Minimum time between `sweep_expired()` passes while the log is busy, so a
+// continuously-written file doesn't pay for an unban scan on every line.
+const SWEEP_MIN_INTERVAL: Duration = Duration::from_millis(500);
+
+fn tail_and_match(path: &str, rules: &[BanRule], guard: &IpGuard) -> TransRResult<()> {
+    let mut file = File::open(path).map_err(TransRError::IoError)?;
+    file.seek(SeekFrom::End(0)).map_err(TransRError::IoError)?;
+    let mut reader = BufReader::new(file);
+    let mut last_swept = Instant::now();
+
+    loop {
+        let mut line = String::new();
+        let read = reader.read_line(&mut line).map_err(TransRError::IoError)?;
+
+        if read == 0 {
+            thread::sleep(Duration::from_millis(500));
+            guard.sweep_expired()?;
+            last_swept = Instant::now();
+            continue;
+        }
+
+        // The file is busy (`read > 0`), so the idle branch above won't run —
+        // sweep here too, on the same throttled cadence, so bans still expire
+        // under continuous log traffic (e.g. an active attack).
+        if last_swept.elapsed() >= SWEEP_MIN_INTERVAL {
+            guard.sweep_expired()?;
+            last_swept = Instant::now();
+        }
+
+        for rule in rules {
+            if let Some(caps) = rule.pattern.captures(&line) {
+                if let Some(ip_match) = caps.get(rule.ip_group) {
+                    if let Ok(ip) = ip_match.as_str().parse::<IpAddr>() {
+                        debug_log!("[IpGuard] matched failure from {} in {}", ip, path);
+                        guard.record_failure(ip)?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn apply_firewall_ban(ip: IpAddr) -> TransRResult<()> {
+    let status = Command::new("nft")
+        .args(["add", "element", "inet", "filter", "ipguard_banned", &format!("{{ {} }}", ip)])
+        .status()
+        .map_err(|e| TransRError::NetworkError(format!("Failed to invoke nft: {}", e)))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(TransRError::NetworkError(format!("nft ban failed for {}", ip)))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn remove_firewall_ban(ip: IpAddr) -> TransRResult<()> {
+    let status = Command::new("nft")
+        .args(["delete", "element", "inet", "filter", "ipguard_banned", &format!("{{ {} }}", ip)])
+        .status()
+        .map_err(|e| TransRError::NetworkError(format!("Failed to invoke nft: {}", e)))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(TransRError::NetworkError(format!("nft unban failed for {}", ip)))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn apply_firewall_ban(ip: IpAddr) -> TransRResult<()> {
+    Err(TransRError::NetworkError(format!(
+        "IP banning via nft/iptables is only supported on Linux (tried to ban {})", ip
+    )))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn remove_firewall_ban(ip: IpAddr) -> TransRResult<()> {
+    Err(TransRError::NetworkError(format!(
+        "IP banning via nft/iptables is only supported on Linux (tried to unban {})", ip
+    )))
+}
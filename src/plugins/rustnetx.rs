@@ -1,15 +1,28 @@
 use crate::errhuman::{TransRError, TransRResult};
 use crate::vvv::Verbose;
-use std::net::{TcpStream, ToSocketAddrs};
-use std::time::Duration;
+use std::net::ToSocketAddrs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use serde::{Serialize, Deserialize};
-use rayon::prelude::*;
+use futures::stream::{self, StreamExt};
+use tokio::net::TcpStream;
+use tokio::sync::Semaphore;
+use tokio::time::timeout;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+    Quic,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PortScanResult {
     pub host: String,
     pub port: u16,
     pub is_open: bool,
+    pub protocol: Protocol,
     pub service: Option<String>,
 }
 
@@ -21,91 +34,215 @@ pub struct HostScanResult {
     pub services: Vec<String>,
 }
 
+// A snapshot of an in-flight `scan_ports` call, handed to a `ProgressCallback`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanProgress {
+    pub scanned: usize,
+    pub total: usize,
+    pub host: String,
+    pub last_port: u16,
+    pub open: bool,
+}
+
+// Called as ports complete so callers (e.g. the pyo3 layer) can surface a
+// progress bar without blocking silently until the whole scan finishes.
+pub type ProgressCallback = Arc<dyn Fn(ScanProgress) + Send + Sync>;
+
+// Don't fire the callback more often than this, even on a scan with a huge
+// port count, so the receiving side isn't spammed.
+const PROGRESS_MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+const DEFAULT_CONCURRENCY: usize = 512;
+
+// Crate-wide runtime shared by `RustNetX::new()`/`with_timeout`/
+// `with_concurrency`, so each scan doesn't spin up its own thread pool —
+// mirrors `AsyncRNet`'s `shared_runtime()` in `func::rnet`.
+static SHARED_RUNTIME: OnceLock<Arc<tokio::runtime::Runtime>> = OnceLock::new();
+
+fn shared_runtime() -> Arc<tokio::runtime::Runtime> {
+    SHARED_RUNTIME.get_or_init(|| {
+        Arc::new(
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .expect("failed to start RustNetX tokio runtime"),
+        )
+    }).clone()
+}
+
 pub struct RustNetX {
     timeout: Duration,
+    concurrency: usize,
+    runtime: Arc<tokio::runtime::Runtime>,
 }
 
 impl RustNetX {
     pub fn new() -> Self {
         RustNetX {
             timeout: Duration::from_millis(500),
+            concurrency: DEFAULT_CONCURRENCY,
+            runtime: shared_runtime(),
         }
     }
-    
+
     pub fn with_timeout(timeout_ms: u64) -> Self {
         RustNetX {
             timeout: Duration::from_millis(timeout_ms),
+            concurrency: DEFAULT_CONCURRENCY,
+            runtime: shared_runtime(),
         }
     }
-    
-    pub fn scan_port(&self, host: &str, port: u16) -> PortScanResult {
-        let address = format!("{}:{}", host, port);
-        
+
+    pub fn with_concurrency(limit: usize) -> Self {
+        RustNetX {
+            timeout: Duration::from_millis(500),
+            concurrency: limit.max(1),
+            runtime: shared_runtime(),
+        }
+    }
+
+    async fn scan_port_async(&self, host: &str, port: u16, semaphore: &Semaphore) -> PortScanResult {
+        let permit = semaphore.acquire().await.expect("semaphore closed");
+
         Verbose::trace(&format!("Scanning {}:{}", host, port));
-        
-        let is_open = match TcpStream::connect_timeout(
-            &address.to_socket_addrs()
-                .ok()
-                .and_then(|mut addrs| addrs.next())
-                .unwrap_or_else(|| {
-                    format!("127.0.0.1:{}", port).parse().unwrap()
-                }),
-            self.timeout,
-        ) {
-            Ok(_) => true,
-            Err(_) => false,
+
+        let address = format!("{}:{}", host, port);
+        let is_open = match timeout(self.timeout, TcpStream::connect(&address)).await {
+            Ok(Ok(_)) => true,
+            _ => false,
         };
-        
+
+        // Release the permit as soon as the probe resolves (or times out) so a
+        // filtered port can't hold a concurrency slot past `self.timeout`.
+        drop(permit);
+
         let service = if is_open {
-            Some(self.identify_service(port))
+            Some(self.identify_service(port, Protocol::Tcp))
         } else {
             None
         };
-        
+
         PortScanResult {
             host: host.to_string(),
             port,
             is_open,
+            protocol: Protocol::Tcp,
             service,
         }
     }
-    
-    pub fn scan_ports(&self, host: &str, ports: Vec<u16>) -> Vec<PortScanResult> {
-        Verbose::info(&format!("🔍 Scanning {} ports on {}", ports.len(), host));
-        
-        ports.par_iter()
-            .map(|&port| self.scan_port(host, port))
+
+    async fn scan_ports_async(
+        &self,
+        host: &str,
+        ports: Vec<u16>,
+        on_progress: Option<ProgressCallback>,
+    ) -> Vec<PortScanResult> {
+        let semaphore = Semaphore::new(self.concurrency);
+        let total = ports.len();
+        let scanned = AtomicUsize::new(0);
+        let last_notified = Mutex::new(Instant::now());
+        // Fire at most every ~1% of progress, but never more often than
+        // PROGRESS_MIN_INTERVAL regardless of how fast ports resolve.
+        let step = (total / 100).max(1);
+        let semaphore = &semaphore;
+
+        stream::iter(ports)
+            .map(|port| {
+                let scanned = &scanned;
+                let last_notified = &last_notified;
+                let on_progress = &on_progress;
+                async move {
+                    let result = self.scan_port_async(host, port, semaphore).await;
+                    let n = scanned.fetch_add(1, Ordering::SeqCst) + 1;
+
+                    if let Some(callback) = on_progress {
+                        let due = n == total || (n % step == 0 && {
+                            let mut last = last_notified.lock().unwrap();
+                            if last.elapsed() >= PROGRESS_MIN_INTERVAL {
+                                *last = Instant::now();
+                                true
+                            } else {
+                                false
+                            }
+                        });
+
+                        if due {
+                            callback(ScanProgress {
+                                scanned: n,
+                                total,
+                                host: host.to_string(),
+                                last_port: result.port,
+                                open: result.is_open,
+                            });
+                        }
+                    }
+
+                    result
+                }
+            })
+            .buffer_unordered(self.concurrency)
             .collect()
+            .await
+    }
+
+    pub fn scan_port(&self, host: &str, port: u16) -> PortScanResult {
+        let semaphore = Semaphore::new(1);
+        self.runtime.block_on(self.scan_port_async(host, port, &semaphore))
+    }
+
+    pub fn scan_ports(&self, host: &str, ports: Vec<u16>) -> Vec<PortScanResult> {
+        Verbose::info(&format!("🔍 Scanning {} ports on {} (concurrency {})", ports.len(), host, self.concurrency));
+
+        self.runtime.block_on(self.scan_ports_async(host, ports, None))
+    }
+
+    pub fn scan_ports_with_progress(
+        &self,
+        host: &str,
+        ports: Vec<u16>,
+        on_progress: ProgressCallback,
+    ) -> Vec<PortScanResult> {
+        Verbose::info(&format!("🔍 Scanning {} ports on {} (concurrency {})", ports.len(), host, self.concurrency));
+
+        self.runtime.block_on(self.scan_ports_async(host, ports, Some(on_progress)))
     }
-    
+
     pub fn scan_port_range(&self, host: &str, start: u16, end: u16) -> Vec<PortScanResult> {
         let ports: Vec<u16> = (start..=end).collect();
         self.scan_ports(host, ports)
     }
-    
+
     pub fn quick_scan(&self, host: &str) -> HostScanResult {
+        self.quick_scan_inner(host, None)
+    }
+
+    pub fn quick_scan_with_progress(&self, host: &str, on_progress: ProgressCallback) -> HostScanResult {
+        self.quick_scan_inner(host, Some(on_progress))
+    }
+
+    fn quick_scan_inner(&self, host: &str, on_progress: Option<ProgressCallback>) -> HostScanResult {
         Verbose::info(&format!("⚡ Quick scan on {}", host));
-        
+
         // Common ports
         let common_ports = vec![
-            21, 22, 23, 25, 53, 80, 110, 143, 443, 445, 
+            21, 22, 23, 25, 53, 80, 110, 143, 443, 445,
             3306, 3389, 5432, 5900, 8080, 8443
         ];
-        
-        let results = self.scan_ports(host, common_ports);
-        
+
+        let results = self.runtime.block_on(self.scan_ports_async(host, common_ports, on_progress));
+
         let open_ports: Vec<u16> = results.iter()
             .filter(|r| r.is_open)
             .map(|r| r.port)
             .collect();
-        
+
         let services: Vec<String> = results.iter()
             .filter(|r| r.is_open && r.service.is_some())
             .map(|r| format!("{}: {}", r.port, r.service.as_ref().unwrap()))
             .collect();
-        
+
         let is_alive = !open_ports.is_empty();
-        
+
         HostScanResult {
             host: host.to_string(),
             is_alive,
@@ -113,24 +250,24 @@ impl RustNetX {
             services,
         }
     }
-    
+
     pub fn full_scan(&self, host: &str) -> HostScanResult {
         Verbose::info(&format!("🔍 Full scan on {} (this may take a while...)", host));
-        
+
         let results = self.scan_port_range(host, 1, 1024);
-        
+
         let open_ports: Vec<u16> = results.iter()
             .filter(|r| r.is_open)
             .map(|r| r.port)
             .collect();
-        
+
         let services: Vec<String> = results.iter()
             .filter(|r| r.is_open && r.service.is_some())
             .map(|r| format!("{}: {}", r.port, r.service.as_ref().unwrap()))
             .collect();
-        
+
         let is_alive = !open_ports.is_empty();
-        
+
         HostScanResult {
             host: host.to_string(),
             is_alive,
@@ -138,8 +275,25 @@ impl RustNetX {
             services,
         }
     }
-    
-    fn identify_service(&self, port: u16) -> String {
+
+    fn identify_service(&self, port: u16, protocol: Protocol) -> String {
+        match protocol {
+            Protocol::Quic => return "HTTP/3".to_string(),
+            Protocol::Udp => {
+                return match port {
+                    53 => "DNS/UDP".to_string(),
+                    67 | 68 => "DHCP".to_string(),
+                    69 => "TFTP".to_string(),
+                    123 => "NTP".to_string(),
+                    161 => "SNMP".to_string(),
+                    500 => "IKE/IPSec".to_string(),
+                    1900 => "SSDP".to_string(),
+                    _ => "Unknown/UDP".to_string(),
+                };
+            }
+            Protocol::Tcp => {}
+        }
+
         match port {
             20 => "FTP-DATA".to_string(),
             21 => "FTP".to_string(),
@@ -164,11 +318,158 @@ impl RustNetX {
             _ => "Unknown".to_string(),
         }
     }
-    
+
+    // Payload sent to probe a UDP port; protocol-appropriate where we know
+    // one, otherwise a minimal generic probe.
+    fn udp_probe_payload(port: u16) -> Vec<u8> {
+        match port {
+            // Minimal DNS query for the root zone, NS record.
+            53 => vec![
+                0xAA, 0xAA, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x02, 0x00, 0x01,
+            ],
+            // NTP client request (mode 3, version 4).
+            123 => {
+                let mut packet = vec![0u8; 48];
+                packet[0] = 0x23;
+                packet
+            }
+            _ => vec![0u8],
+        }
+    }
+
+    // Probes a single UDP port. A "connected" UDP socket surfaces the
+    // remote's ICMP port-unreachable as an error on `recv`, which lets us
+    // tell a closed port apart from one that's simply not replying without
+    // needing a raw socket to watch for ICMP ourselves.
+    async fn scan_udp_port_async(&self, host: &str, port: u16, semaphore: &Semaphore) -> PortScanResult {
+        let permit = semaphore.acquire().await.expect("semaphore closed");
+
+        Verbose::trace(&format!("UDP scanning {}:{}", host, port));
+
+        let is_open = match tokio::net::UdpSocket::bind("0.0.0.0:0").await {
+            Ok(socket) => {
+                let address = format!("{}:{}", host, port);
+                if socket.connect(&address).await.is_ok() {
+                    let payload = Self::udp_probe_payload(port);
+                    let _ = socket.send(&payload).await;
+
+                    let mut buf = [0u8; 512];
+                    match timeout(self.timeout, socket.recv(&mut buf)).await {
+                        Ok(Ok(_)) => true,      // got a reply: definitely open
+                        Ok(Err(_)) => false,    // ICMP port-unreachable surfaced as a recv error: closed
+                        Err(_) => true,         // no reply within the timeout: open|filtered, counted as open
+                    }
+                } else {
+                    false
+                }
+            }
+            Err(_) => false,
+        };
+
+        drop(permit);
+
+        let service = if is_open {
+            Some(self.identify_service(port, Protocol::Udp))
+        } else {
+            None
+        };
+
+        PortScanResult {
+            host: host.to_string(),
+            port,
+            is_open,
+            protocol: Protocol::Udp,
+            service,
+        }
+    }
+
+    // Builds a minimal QUIC long-header Initial packet. It won't carry a
+    // valid TLS ClientHello, but real QUIC stacks validate the header
+    // (version, connection-ID lengths, 1200-byte minimum) before touching
+    // the encrypted payload, so a compliant server still answers with a
+    // Version Negotiation or Initial packet — enough to fingerprint QUIC.
+    fn quic_probe_packet() -> Vec<u8> {
+        let mut packet = vec![0xC3, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00];
+        packet.resize(1200, 0x00);
+        packet
+    }
+
+    async fn scan_quic_port_async(&self, host: &str, port: u16, semaphore: &Semaphore) -> PortScanResult {
+        let permit = semaphore.acquire().await.expect("semaphore closed");
+
+        Verbose::trace(&format!("QUIC probing {}:{}", host, port));
+
+        let is_quic = match tokio::net::UdpSocket::bind("0.0.0.0:0").await {
+            Ok(socket) => {
+                let address = format!("{}:{}", host, port);
+                if socket.connect(&address).await.is_ok() {
+                    let packet = Self::quic_probe_packet();
+                    let _ = socket.send(&packet).await;
+
+                    let mut buf = [0u8; 512];
+                    matches!(timeout(self.timeout, socket.recv(&mut buf)).await, Ok(Ok(_)))
+                } else {
+                    false
+                }
+            }
+            Err(_) => false,
+        };
+
+        drop(permit);
+
+        PortScanResult {
+            host: host.to_string(),
+            port,
+            is_open: is_quic,
+            protocol: Protocol::Quic,
+            service: if is_quic { Some(self.identify_service(port, Protocol::Quic)) } else { None },
+        }
+    }
+
+    pub fn scan_udp(&self, host: &str, ports: Vec<u16>) -> Vec<PortScanResult> {
+        Verbose::info(&format!("🔍 UDP scanning {} ports on {} (concurrency {})", ports.len(), host, self.concurrency));
+
+        self.runtime.block_on(async {
+            let semaphore = Semaphore::new(self.concurrency);
+            stream::iter(ports)
+                .map(|port| self.scan_udp_port_async(host, port, &semaphore))
+                .buffer_unordered(self.concurrency)
+                .collect()
+                .await
+        })
+    }
+
+    // Probes the QUIC/HTTP3 ports (443, 8443) since that's where HTTP/3
+    // endpoints live; plain TCP-only services on those ports just won't reply.
+    pub fn scan_quic(&self, host: &str) -> Vec<PortScanResult> {
+        Verbose::info(&format!("🔍 QUIC/HTTP3 probing {} on ports 443, 8443", host));
+
+        self.runtime.block_on(async {
+            let semaphore = Semaphore::new(self.concurrency);
+            stream::iter([443u16, 8443u16])
+                .map(|port| self.scan_quic_port_async(host, port, &semaphore))
+                .buffer_unordered(self.concurrency)
+                .collect()
+                .await
+        })
+    }
+
+    // Runs TCP, UDP, and QUIC probes over `ports` and merges the results so
+    // UDP-only and HTTP/3-only services aren't invisible next to the TCP view.
+    pub fn scan_combined(&self, host: &str, ports: Vec<u16>) -> Vec<PortScanResult> {
+        let mut results = self.scan_ports(host, ports.clone());
+        results.extend(self.scan_udp(host, ports));
+        results.extend(self.scan_quic(host));
+        results
+    }
+
     pub fn banner_grab(&self, host: &str, port: u16) -> TransRResult<String> {
+        use std::net::TcpStream as StdTcpStream;
+
         let address = format!("{}:{}", host, port);
-        
-        match TcpStream::connect_timeout(
+
+        match StdTcpStream::connect_timeout(
             &address.to_socket_addrs()
                 .map_err(|e| TransRError::NetworkError(format!("Invalid address: {}", e)))?
                 .next()
@@ -178,9 +479,9 @@ impl RustNetX {
             Ok(stream) => {
                 use std::io::Read;
                 let mut buffer = [0u8; 1024];
-                
+
                 stream.set_read_timeout(Some(self.timeout)).ok();
-                
+
                 match (&stream).read(&mut buffer) {
                     Ok(n) if n > 0 => {
                         let banner = String::from_utf8_lossy(&buffer[..n]);
@@ -0,0 +1,254 @@
+use crate::errhuman::{TransRError, TransRResult};
+use crate::vvv::Verbose;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// Run-state of a registered worker, as surfaced by `WorkerManager::list()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkerState {
+    Busy,
+    Idle,
+    Done,
+    Errored(String),
+}
+
+// Message sent on a worker's control channel, checked between iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlMessage {
+    Start,
+    Pause,
+    Resume,
+    Cancel,
+}
+
+// A unit of background work the `@async`/`@retry` decorators can attach to.
+pub trait Worker: Send {
+    fn work(&mut self) -> TransRResult<WorkerState>;
+}
+
+// Snapshot of a worker's run-state for `WorkerManager::list()`.
+#[derive(Debug, Clone)]
+pub struct WorkerInfo {
+    pub name: String,
+    pub state: WorkerState,
+    pub error_count: u32,
+    pub last_run: Option<Instant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct WorkItem(String);
+
+struct WorkerSlot {
+    worker: Arc<Mutex<Box<dyn Worker>>>,
+    interval: Duration,
+    state: Arc<Mutex<WorkerState>>,
+    error_count: Arc<Mutex<u32>>,
+    last_run: Arc<Mutex<Option<Instant>>>,
+}
+
+// Owns a set of registered workers and runs them on a drift-free periodic
+// schedule: a `BTreeMap<Instant, HashSet<WorkItem>>` next-run queue is popped
+// at its earliest key, each due worker runs on its own short-lived thread,
+// and the slot is reinserted at `now + interval`.
+pub struct WorkerManager {
+    slots: Arc<Mutex<HashMap<String, WorkerSlot>>>,
+    control_tx: Sender<(String, ControlMessage)>,
+    control_rx: Option<Receiver<(String, ControlMessage)>>,
+}
+
+impl WorkerManager {
+    pub fn new() -> Self {
+        let (control_tx, control_rx) = mpsc::channel();
+        WorkerManager {
+            slots: Arc::new(Mutex::new(HashMap::new())),
+            control_tx,
+            control_rx: Some(control_rx),
+        }
+    }
+
+    pub fn register<W: Worker + 'static>(&self, name: &str, worker: W, interval: Duration) {
+        self.slots.lock().unwrap().insert(name.to_string(), WorkerSlot {
+            worker: Arc::new(Mutex::new(Box::new(worker))),
+            interval,
+            state: Arc::new(Mutex::new(WorkerState::Idle)),
+            error_count: Arc::new(Mutex::new(0)),
+            last_run: Arc::new(Mutex::new(None)),
+        });
+    }
+
+    // Sends a `Start`/`Pause`/`Resume`/`Cancel` message to a worker's control
+    // channel; it's picked up between iterations of the schedule loop.
+    pub fn control(&self, name: &str, message: ControlMessage) -> TransRResult<()> {
+        if !self.slots.lock().unwrap().contains_key(name) {
+            return Err(TransRError::ValidationError(format!("Unknown worker: {}", name)));
+        }
+
+        self.control_tx.send((name.to_string(), message))
+            .map_err(|e| TransRError::NetworkError(format!("Worker control channel closed: {}", e)))
+    }
+
+    pub fn list(&self) -> Vec<WorkerInfo> {
+        self.slots.lock().unwrap().iter()
+            .map(|(name, slot)| WorkerInfo {
+                name: name.clone(),
+                state: slot.state.lock().unwrap().clone(),
+                error_count: *slot.error_count.lock().unwrap(),
+                last_run: *slot.last_run.lock().unwrap(),
+            })
+            .collect()
+    }
+
+    // Spawns the schedule loop on a background thread and returns its handle.
+    // `self` stays usable afterwards for further `register`/`control`/`list` calls.
+    pub fn start(&mut self) -> JoinHandle<()> {
+        let control_rx = self.control_rx.take().expect("WorkerManager::start called more than once");
+        let slots = self.slots.clone();
+
+        thread::spawn(move || Self::run_schedule(slots, control_rx))
+    }
+
+    fn run_schedule(slots: Arc<Mutex<HashMap<String, WorkerSlot>>>, control_rx: Receiver<(String, ControlMessage)>) {
+        let mut schedule: BTreeMap<Instant, HashSet<WorkItem>> = BTreeMap::new();
+        let mut paused: HashSet<String> = HashSet::new();
+        let mut cancelled: HashSet<String> = HashSet::new();
+
+        loop {
+            // Refill from the registered worker list whenever the queue is
+            // empty (at startup, or while no worker is scheduled). A worker
+            // registered while others are already queued is not added until
+            // the queue next runs dry.
+            if schedule.is_empty() {
+                let now = Instant::now();
+                for name in slots.lock().unwrap().keys() {
+                    if !cancelled.contains(name) {
+                        schedule.entry(now).or_insert_with(HashSet::new).insert(WorkItem(name.clone()));
+                    }
+                }
+            }
+
+            let next_run = match schedule.keys().next().copied() {
+                Some(at) => at,
+                None => {
+                    if let Ok((name, message)) = control_rx.recv_timeout(Duration::from_millis(200)) {
+                        Self::apply_control(&name, message, &mut paused, &mut cancelled, &slots);
+                    }
+                    continue;
+                }
+            };
+
+            let now = Instant::now();
+            if next_run > now {
+                // Sleep until the next slot is due, or wake early on a control message.
+                if let Ok((name, message)) = control_rx.recv_timeout(next_run - now) {
+                    Self::apply_control(&name, message, &mut paused, &mut cancelled, &slots);
+                    continue;
+                }
+            } else {
+                while let Ok((name, message)) = control_rx.try_recv() {
+                    Self::apply_control(&name, message, &mut paused, &mut cancelled, &slots);
+                }
+            }
+
+            let due = schedule.remove(&next_run).unwrap_or_default();
+
+            for WorkItem(name) in due {
+                // Cancelled workers keep their slot (so `list()` still reports
+                // them as `Done`), but drop out of the schedule for good.
+                if cancelled.contains(&name) {
+                    continue;
+                }
+
+                let interval = {
+                    let slots_guard = slots.lock().unwrap();
+                    let slot = match slots_guard.get(&name) {
+                        Some(slot) => slot,
+                        None => continue, // never registered / removed
+                    };
+
+                    if paused.contains(&name) {
+                        None
+                    } else if *slot.state.lock().unwrap() == WorkerState::Busy {
+                        // The previous run is still in flight (it overran its
+                        // interval) — skip spawning another thread this tick
+                        // instead of piling up threads blocked on `slot.worker`'s
+                        // mutex, and check back soon instead of at `interval`.
+                        Verbose::debug(&format!("Worker '{}' still busy, skipping this tick", name));
+                        None
+                    } else {
+                        Self::run_once(&name, slot);
+                        Some(slot.interval)
+                    }
+                };
+
+                let reinsert_at = match interval {
+                    Some(interval) => Instant::now() + interval,
+                    None => Instant::now() + Duration::from_millis(250), // paused/busy: check back soon
+                };
+
+                schedule.entry(reinsert_at).or_insert_with(HashSet::new).insert(WorkItem(name));
+            }
+        }
+    }
+
+    fn apply_control(
+        name: &str,
+        message: ControlMessage,
+        paused: &mut HashSet<String>,
+        cancelled: &mut HashSet<String>,
+        slots: &Arc<Mutex<HashMap<String, WorkerSlot>>>,
+    ) {
+        match message {
+            ControlMessage::Pause => {
+                paused.insert(name.to_string());
+            }
+            ControlMessage::Resume | ControlMessage::Start => {
+                paused.remove(name);
+            }
+            ControlMessage::Cancel => {
+                paused.remove(name);
+                cancelled.insert(name.to_string());
+                // Leave the slot in place (just marked `Done`) so `list()`
+                // keeps reporting it instead of making it vanish.
+                if let Some(slot) = slots.lock().unwrap().get(name) {
+                    *slot.state.lock().unwrap() = WorkerState::Done;
+                }
+            }
+        }
+    }
+
+    fn run_once(name: &str, slot: &WorkerSlot) {
+        *slot.state.lock().unwrap() = WorkerState::Busy;
+
+        let worker = slot.worker.clone();
+        let state = slot.state.clone();
+        let error_count = slot.error_count.clone();
+        let last_run = slot.last_run.clone();
+        let name = name.to_string();
+
+        thread::spawn(move || {
+            let result = worker.lock().unwrap().work();
+            *last_run.lock().unwrap() = Some(Instant::now());
+
+            match result {
+                Ok(new_state) => {
+                    *state.lock().unwrap() = new_state;
+                    *error_count.lock().unwrap() = 0;
+                }
+                Err(e) => {
+                    Verbose::error(&format!("Worker '{}' errored: {}", name, e));
+                    *error_count.lock().unwrap() += 1;
+                    *state.lock().unwrap() = WorkerState::Errored(e.to_string());
+                }
+            }
+        });
+    }
+}
+
+impl Default for WorkerManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
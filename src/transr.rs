@@ -1,20 +1,34 @@
 use crate::errhuman::{TransRError, TransRResult};
 use crate::vvv::Verbose;
 use crate::debug::DebugMode;
-use std::collections::HashMap;
+use crate::plugins::rustnetx::ProgressCallback;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Task {
     Print(String),
     SystemCheck,
     TimeCheck,
     MathCalc { operation: String, args: Vec<f64> },
-    NetworkRequest { method: String, url: String, data: Option<String> },
+    NetworkRequest {
+        method: String,
+        url: String,
+        data: Option<String>,
+        max_retries: Option<u32>,
+        cache_ttl_secs: Option<u64>,
+    },
     PortScan { host: String, ports: Vec<u16> },
     VulnScan { url: String },
     CryptoHash { algorithm: String, data: String },
     Loop { iterations: u32, operation: String },
+    MineBlock { header: String, difficulty: u32, max_nonce: u64 },
+    MerkleRoot { leaves: Vec<String>, algorithm: String },
     Custom { name: String, args: HashMap<String, String> },
 }
 
@@ -26,11 +40,35 @@ pub struct PipelineResult {
     pub duration_ms: u128,
 }
 
+// Emitted at each boundary of `run()` so external code (a UI, a logger) can
+// observe execution live instead of waiting for the bulk result at the end.
+#[derive(Debug, Clone)]
+pub enum PipelineEvent {
+    TaskStarted { index: usize, name: String, timestamp_us: i64 },
+    TaskFinished { index: usize, success: bool, duration_ms: u128, timestamp_us: i64 },
+    PipelineFinished { total: usize, timestamp_us: i64 },
+}
+
+// A task recurring on `interval`, reinserted into the schedule after each run.
+#[derive(Debug, Clone, PartialEq)]
+struct ScheduledTask {
+    task: Task,
+    interval: Duration,
+}
+
+// Tasks due at the same instant; deduped so the same host/port scan etc.
+// never gets enqueued twice for a single slot.
+type TaskSet = Vec<ScheduledTask>;
+
 pub struct TransRPipeline {
     pub tasks: Vec<Task>,
     pub results: Vec<PipelineResult>,
     pub verbose_level: u8,
     pub debug_mode: bool,
+    scan_progress: Option<ProgressCallback>,
+    schedule: BTreeMap<Instant, TaskSet>,
+    cycle: u32,
+    event_sender: Option<mpsc::Sender<PipelineEvent>>,
 }
 
 impl TransRPipeline {
@@ -40,9 +78,19 @@ impl TransRPipeline {
             results: Vec::new(),
             verbose_level: 1,
             debug_mode: false,
+            scan_progress: None,
+            schedule: BTreeMap::new(),
+            cycle: 0,
+            event_sender: None,
         }
     }
-    
+
+    // Subscribes `tx` to `TaskStarted`/`TaskFinished`/`PipelineFinished`
+    // events emitted by `run()`, so a UI or logger can follow progress live.
+    pub fn with_event_sender(&mut self, tx: mpsc::Sender<PipelineEvent>) {
+        self.event_sender = Some(tx);
+    }
+
     pub fn set_verbose(&mut self, level: u8) {
         self.verbose_level = level;
         Verbose::set_level(level);
@@ -78,16 +126,34 @@ impl TransRPipeline {
     }
     
     pub fn http_get(&mut self, url: String) {
+        self.http_get_with_policy(url, None, None);
+    }
+
+    // Like `http_get`, but lets the caller opt this request into `RNet`'s
+    // retry/cache behavior — the counterpart to `RNet::with_retry`/
+    // `with_cache` for requests queued on the pipeline instead of issued
+    // directly.
+    pub fn http_get_with_policy(&mut self, url: String, max_retries: Option<u32>, cache_ttl_secs: Option<u64>) {
         self.add_task(Task::NetworkRequest {
             method: "GET".to_string(),
             url,
             data: None,
+            max_retries,
+            cache_ttl_secs,
         });
     }
     
     pub fn port_scan(&mut self, host: String, ports: Vec<u16>) {
         self.add_task(Task::PortScan { host, ports });
     }
+
+    // Callback used for the next `PortScan` task(s) this pipeline runs, so
+    // Python callers get progress updates instead of a silent multi-second
+    // block. Pass `None` to clear a previously set callback — otherwise it
+    // would stick around and silently apply to an unrelated later scan.
+    pub fn set_scan_progress(&mut self, callback: Option<ProgressCallback>) {
+        self.scan_progress = callback;
+    }
     
     pub fn vuln_scan(&mut self, url: String) {
         self.add_task(Task::VulnScan { url });
@@ -96,6 +162,12 @@ impl TransRPipeline {
     pub fn hash(&mut self, algorithm: String, data: String) {
         self.add_task(Task::CryptoHash { algorithm, data });
     }
+
+    // Builds a binary Merkle tree over `leaves` and queues a task that
+    // resolves to its root digest.
+    pub fn merkle(&mut self, leaves: Vec<String>, algorithm: String) {
+        self.add_task(Task::MerkleRoot { leaves, algorithm });
+    }
     
     pub fn heavy_loop(&mut self, iterations: u32) {
         self.add_task(Task::Loop {
@@ -104,11 +176,20 @@ impl TransRPipeline {
         });
     }
     
+    // Mines a real block of proof-of-work: `weight` sets the difficulty
+    // (leading zero bits required of the Keccak256 hash) and `height` is
+    // folded into the block header.
     pub fn blockchain_loop(&mut self, height: u32, weight: u32) {
-        let total_iterations = height * weight;
-        self.add_task(Task::Loop {
-            iterations: total_iterations,
-            operation: "blockchain".to_string(),
+        self.mine(format!("block-{}", height), weight);
+    }
+
+    // Searches for a nonce such that `keccak256(header || nonce)` has at
+    // least `difficulty` leading zero bits.
+    pub fn mine(&mut self, header: String, difficulty: u32) {
+        self.add_task(Task::MineBlock {
+            header,
+            difficulty,
+            max_nonce: 10_000_000,
         });
     }
     
@@ -116,178 +197,494 @@ impl TransRPipeline {
         self.tasks.clear();
         self.results.clear();
     }
-    
+
+    // Schedules `task` to run immediately, then again every `interval`.
+    pub fn schedule(&mut self, task: Task, interval: Duration) {
+        self.insert_scheduled(Instant::now(), ScheduledTask { task, interval });
+    }
+
+    // Merges `entry` into the `TaskSet` at `at`, deduping identical tasks
+    // already queued for that slot (same host/port scan etc. isn't enqueued twice).
+    fn insert_scheduled(&mut self, at: Instant, entry: ScheduledTask) {
+        let set = self.schedule.entry(at).or_insert_with(Vec::new);
+        if !set.iter().any(|queued| queued.task == entry.task) {
+            set.push(entry);
+        }
+    }
+
+    // Runs the schedule set up by `schedule()` forever (or for
+    // `max_iterations` cycles), popping the earliest due slot, sleeping
+    // until it's due if needed, executing it, and reinserting each task at
+    // `now + interval`. Each cycle's results are tagged so `get_results()`
+    // can be diffed across runs.
+    pub fn run_forever(&mut self, max_iterations: Option<u32>) -> TransRResult<()> {
+        Verbose::info("🚀 [TransR Engine]: Scheduler Starting...");
+
+        loop {
+            if let Some(max) = max_iterations {
+                if self.cycle >= max {
+                    break;
+                }
+            }
+
+            let next_run = match self.schedule.keys().next().copied() {
+                Some(at) => at,
+                None => break,
+            };
+
+            let now = Instant::now();
+            if next_run > now {
+                std::thread::sleep(next_run - now);
+            }
+
+            let due = self.schedule.remove(&next_run).unwrap_or_default();
+            self.cycle += 1;
+            let cycle_timestamp = crate::func::timeru::timestamp();
+
+            for scheduled in &due {
+                Verbose::debug(&format!("Cycle {}: {:?}", self.cycle, scheduled.task));
+
+                let start = Instant::now();
+                let result = self.execute_task(&scheduled.task);
+                let duration = start.elapsed().as_millis();
+
+                let (success, output) = match result {
+                    Ok(output) => (true, output),
+                    Err(e) => {
+                        Verbose::error(&format!("Cycle {}: task failed: {}", self.cycle, e));
+                        (false, e.to_string())
+                    }
+                };
+
+                self.results.push(PipelineResult {
+                    task_name: format!("[cycle {} @ {}] {:?}", self.cycle, cycle_timestamp, scheduled.task),
+                    success,
+                    output,
+                    duration_ms: duration,
+                });
+            }
+
+            for scheduled in due {
+                let next_run = Instant::now() + scheduled.interval;
+                self.insert_scheduled(next_run, scheduled);
+            }
+        }
+
+        Verbose::success(&format!("✅ [TransR Engine]: Scheduler Finished after {} cycle(s).", self.cycle));
+        Ok(())
+    }
+
+    fn emit_event(&self, event: PipelineEvent) {
+        if let Some(tx) = &self.event_sender {
+            let _ = tx.send(event);
+        }
+    }
+
     pub fn run(&mut self) -> TransRResult<()> {
         Verbose::info("🚀 [TransR Engine]: Pipeline Starting...");
-        
+
         let total_tasks = self.tasks.len();
-        
+
         for (idx, task) in self.tasks.iter().enumerate() {
             Verbose::debug(&format!("Task {}/{}: {:?}", idx + 1, total_tasks, task));
-            
+
+            self.emit_event(PipelineEvent::TaskStarted {
+                index: idx,
+                name: format!("{:?}", task),
+                timestamp_us: crate::func::timeru::timestamp_us(),
+            });
+
             let start = std::time::Instant::now();
-            let result = self.execute_task(task)?;
+            let result = self.execute_task(task);
             let duration = start.elapsed().as_millis();
-            
-            self.results.push(PipelineResult {
-                task_name: format!("{:?}", task),
-                success: true,
-                output: result,
+
+            self.emit_event(PipelineEvent::TaskFinished {
+                index: idx,
+                success: result.is_ok(),
                 duration_ms: duration,
+                timestamp_us: crate::func::timeru::timestamp_us(),
             });
+
+            match result {
+                Ok(output) => self.results.push(PipelineResult {
+                    task_name: format!("{:?}", task),
+                    success: true,
+                    output,
+                    duration_ms: duration,
+                }),
+                Err(e) => {
+                    self.results.push(PipelineResult {
+                        task_name: format!("{:?}", task),
+                        success: false,
+                        output: e.to_string(),
+                        duration_ms: duration,
+                    });
+                    return Err(e);
+                }
+            }
         }
-        
+
         self.tasks.clear();
         Verbose::success(&format!("✅ [TransR Engine]: Pipeline Finished. Executed {} tasks.", total_tasks));
-        
+
+        self.emit_event(PipelineEvent::PipelineFinished {
+            total: total_tasks,
+            timestamp_us: crate::func::timeru::timestamp_us(),
+        });
+
         Ok(())
     }
-    
-    fn execute_task(&self, task: &Task) -> TransRResult<String> {
-        match task {
-            Task::Print(text) => {
-                println!("📝 Output: {}", text);
-                Ok(format!("Printed: {}", text))
-            }
-            
-            Task::SystemCheck => {
-                use crate::func::sysez::SysEz;
-                let mut sys = SysEz::new();
-                let info = sys.get_system_info()?;
-                
-                println!("⚙️  System: {} ({})", info.os_name, info.os_version);
-                println!("   CPU: {} ({}cores)", info.cpu_brand, info.cpu_count);
-                println!("   RAM: {:.2}GB / {:.2}GB", 
-                    info.used_memory as f64 / 1024.0 / 1024.0 / 1024.0,
-                    info.total_memory as f64 / 1024.0 / 1024.0 / 1024.0
-                );
-                
-                Ok("System check completed".to_string())
-            }
-            
-            Task::TimeCheck => {
-                use crate::func::nowtime::NowTime;
-                let now = NowTime::now();
-                
-                println!("🕐 Time: {}", now.formatted);
-                println!("   Period: {}", now.time_of_day());
-                println!("   Week: {}", now.iso_week);
-                
-                Ok(format!("Time: {}", now.formatted))
-            }
-            
-            Task::MathCalc { operation, args } => {
-                use crate::func::rmath::RMath;
-                
-                let result = match operation.as_str() {
-                    "sum" => RMath::sum(args),
-                    "mean" => RMath::mean(args)?,
-                    "max" => RMath::max(args)?,
-                    "min" => RMath::min(args)?,
-                    _ => return Err(TransRError::MathError(format!("Unknown operation: {}", operation))),
-                };
-                
-                println!("🔢 Math Result ({}): {}", operation, result);
-                Ok(format!("{}", result))
-            }
-            
-            Task::NetworkRequest { method, url, data } => {
-                use crate::func::rnet::RNet;
-                let client = RNet::new()?;
-                
-                let response = match method.as_str() {
-                    "GET" => client.get(url)?,
-                    "POST" => client.post(url, data.as_ref().unwrap_or(&"{}".to_string()))?,
-                    _ => return Err(TransRError::NetworkError(format!("Unsupported method: {}", method))),
+
+    // Runs all queued tasks across up to `max_concurrency` worker threads
+    // instead of strictly sequentially. Each task's stdout output is
+    // buffered and flushed once collected, in submission order, so
+    // concurrent tasks' logs don't interleave; `duration_ms` still
+    // reflects that task's own wall-clock time.
+    pub fn run_parallel(&mut self, max_concurrency: usize) -> TransRResult<()> {
+        Verbose::info("🚀 [TransR Engine]: Pipeline Starting (parallel)...");
+
+        let tasks: Vec<Task> = self.tasks.drain(..).collect();
+        let total_tasks = tasks.len();
+
+        if total_tasks == 0 {
+            Verbose::success("✅ [TransR Engine]: Pipeline Finished (parallel). Executed 0 tasks.");
+            return Ok(());
+        }
+
+        let scan_progress = self.scan_progress.clone();
+        let worker_count = max_concurrency.max(1).min(total_tasks);
+
+        let (job_tx, job_rx) = mpsc::channel::<(usize, Task)>();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        let (result_tx, result_rx) = mpsc::channel::<(usize, Task, TransRResult<String>, u128, String)>();
+
+        let mut workers = Vec::with_capacity(worker_count);
+        for _ in 0..worker_count {
+            let job_rx = job_rx.clone();
+            let result_tx = result_tx.clone();
+            let scan_progress = scan_progress.clone();
+
+            workers.push(thread::spawn(move || loop {
+                let job = job_rx.lock().unwrap().recv();
+                let (index, task) = match job {
+                    Ok(job) => job,
+                    Err(_) => break,
                 };
-                
-                println!("🌐 HTTP Response: Status {}", response.status);
-                Ok(format!("Status: {}", response.status))
-            }
-            
-            Task::PortScan { host, ports } => {
-                use crate::plugins::rustnetx::RustNetX;
-                let scanner = RustNetX::new();
-                let results = scanner.scan_ports(host, ports.clone());
-                
-                let open_count = results.iter().filter(|r| r.is_open).count();
-                println!("🔍 Port Scan: {} open / {} total", open_count, results.len());
-                
-                Ok(format!("{} open ports", open_count))
+
+                let mut buffer: Vec<u8> = Vec::new();
+                let start = Instant::now();
+                let result = execute_task(&scan_progress, &task, &mut buffer);
+                let duration = start.elapsed().as_millis();
+                let output = String::from_utf8_lossy(&buffer).into_owned();
+
+                if result_tx.send((index, task, result, duration, output)).is_err() {
+                    break;
+                }
+            }));
+        }
+        drop(result_tx);
+
+        for (index, task) in tasks.into_iter().enumerate() {
+            job_tx.send((index, task))
+                .map_err(|e| TransRError::PipelineError(format!("Worker pool closed: {}", e)))?;
+        }
+        drop(job_tx);
+
+        let mut collected: Vec<Option<(Task, TransRResult<String>, u128, String)>> =
+            (0..total_tasks).map(|_| None).collect();
+        for (index, task, result, duration, output) in result_rx {
+            collected[index] = Some((task, result, duration, output));
+        }
+
+        for worker in workers {
+            let _ = worker.join();
+        }
+
+        let mut first_err = None;
+
+        for entry in collected {
+            let (task, result, duration, output) = entry.expect("every submitted task reports a result");
+            print!("{}", output);
+
+            match result {
+                Ok(value) => self.results.push(PipelineResult {
+                    task_name: format!("{:?}", task),
+                    success: true,
+                    output: value,
+                    duration_ms: duration,
+                }),
+                Err(e) => {
+                    Verbose::error(&format!("Task failed: {}", e));
+                    self.results.push(PipelineResult {
+                        task_name: format!("{:?}", task),
+                        success: false,
+                        output: e.to_string(),
+                        duration_ms: duration,
+                    });
+                    if first_err.is_none() {
+                        first_err = Some(e);
+                    }
+                }
             }
-            
-            Task::VulnScan { url } => {
-                use crate::plugins::hyros::HyrOS;
-                let scanner = HyrOS::new()?;
-                let results = scanner.scan(url);
-                
-                let vuln_count = results.iter().filter(|r| r.matched).count();
-                println!("🔐 Vulnerability Scan: {} vulnerabilities found", vuln_count);
-                
-                Ok(format!("{} vulnerabilities", vuln_count))
+        }
+
+        Verbose::success(&format!("✅ [TransR Engine]: Pipeline Finished (parallel). Executed {} tasks.", total_tasks));
+
+        match first_err {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn execute_task(&self, task: &Task) -> TransRResult<String> {
+        execute_task(&self.scan_progress, task, &mut std::io::stdout())
+    }
+
+    pub fn get_results(&self) -> &[PipelineResult] {
+        &self.results
+    }
+}
+
+// Runs a single task, writing any task output to `out` instead of going
+// straight to stdout — `run()` hands it `std::io::stdout()` directly,
+// while `run_parallel()` hands it a per-task buffer so concurrent tasks'
+// output doesn't interleave.
+fn execute_task(scan_progress: &Option<ProgressCallback>, task: &Task, out: &mut impl std::io::Write) -> TransRResult<String> {
+    match task {
+        Task::Print(text) => {
+            let _ = writeln!(out, "📝 Output: {}", text);
+            Ok(format!("Printed: {}", text))
+        }
+
+        Task::SystemCheck => {
+            use crate::func::sysez::SysEz;
+            let mut sys = SysEz::new();
+            let info = sys.get_system_info()?;
+
+            let _ = writeln!(out, "⚙️  System: {} ({})", info.os_name, info.os_version);
+            let _ = writeln!(out, "   CPU: {} ({}cores)", info.cpu_brand, info.cpu_count);
+            let _ = writeln!(out, "   RAM: {:.2}GB / {:.2}GB",
+                info.used_memory as f64 / 1024.0 / 1024.0 / 1024.0,
+                info.total_memory as f64 / 1024.0 / 1024.0 / 1024.0
+            );
+
+            Ok("System check completed".to_string())
+        }
+
+        Task::TimeCheck => {
+            use crate::func::nowtime::NowTime;
+            let now = NowTime::now();
+
+            let _ = writeln!(out, "🕐 Time: {}", now.formatted);
+            let _ = writeln!(out, "   Period: {}", now.time_of_day());
+            let _ = writeln!(out, "   Week: {}", now.iso_week);
+
+            Ok(format!("Time: {}", now.formatted))
+        }
+
+        Task::MathCalc { operation, args } => {
+            use crate::func::rmath::RMath;
+
+            let result = match operation.as_str() {
+                "sum" => RMath::sum(args),
+                "mean" => RMath::mean(args)?,
+                "max" => RMath::max(args)?,
+                "min" => RMath::min(args)?,
+                _ => return Err(TransRError::MathError(format!("Unknown operation: {}", operation))),
+            };
+
+            let _ = writeln!(out, "🔢 Math Result ({}): {}", operation, result);
+            Ok(format!("{}", result))
+        }
+
+        Task::NetworkRequest { method, url, data, max_retries, cache_ttl_secs } => {
+            use crate::func::rnet::{RNet, RetryPolicy};
+            let mut client = RNet::new()?;
+
+            if let Some(max_retries) = max_retries {
+                client = client.with_retry(RetryPolicy { max_retries: *max_retries, ..RetryPolicy::default() });
             }
-            
-            Task::CryptoHash { algorithm, data } => {
-                use sha2::{Sha256, Digest};
-                use md5::Md5;
-                
-                let hash = match algorithm.as_str() {
-                    "sha256" => {
-                        let mut hasher = Sha256::new();
-                        hasher.update(data.as_bytes());
-                        format!("{:x}", hasher.finalize())
-                    }
-                    "md5" => {
-                        let mut hasher = Md5::new();
-                        hasher.update(data.as_bytes());
-                        format!("{:x}", hasher.finalize())
-                    }
-                    _ => return Err(TransRError::CryptoError(format!("Unknown algorithm: {}", algorithm))),
-                };
-                
-                println!("🔐 Hash ({}): {}", algorithm, hash);
-                Ok(hash)
+            if let Some(cache_ttl_secs) = cache_ttl_secs {
+                client = client.with_cache(Duration::from_secs(*cache_ttl_secs));
             }
-            
-            Task::Loop { iterations, operation } => {
-                let mut val: u32 = 0;
-                
-                match operation.as_str() {
-                    "xor" => {
-                        for i in 0..*iterations {
-                            val = (val.wrapping_add(i)) ^ 0x12345678;
-                        }
-                    }
-                    "blockchain" => {
-                        use rand::Rng;
-                        let mut rng = rand::thread_rng();
-                        
-                        for _ in 0..*iterations {
-                            val = (val.wrapping_add(rng.gen::<u32>())) ^ 0xDEADBEEF;
-                        }
+
+            let response = match method.as_str() {
+                "GET" => client.get(url)?,
+                "POST" => client.post(url, data.as_ref().unwrap_or(&"{}".to_string()))?,
+                _ => return Err(TransRError::NetworkError(format!("Unsupported method: {}", method))),
+            };
+
+            let _ = writeln!(out, "🌐 HTTP Response: Status {}", response.status);
+            Ok(format!("Status: {}", response.status))
+        }
+
+        Task::PortScan { host, ports } => {
+            use crate::plugins::rustnetx::RustNetX;
+            let scanner = RustNetX::new();
+            let results = match scan_progress {
+                Some(callback) => scanner.scan_ports_with_progress(host, ports.clone(), callback.clone()),
+                None => scanner.scan_ports(host, ports.clone()),
+            };
+
+            let open_count = results.iter().filter(|r| r.is_open).count();
+            let _ = writeln!(out, "🔍 Port Scan: {} open / {} total", open_count, results.len());
+
+            Ok(format!("{} open ports", open_count))
+        }
+
+        Task::VulnScan { url } => {
+            use crate::plugins::hyros::HyrOS;
+            let scanner = HyrOS::new()?;
+            let results = scanner.scan(url);
+
+            let vuln_count = results.iter().filter(|r| r.matched).count();
+            let _ = writeln!(out, "🔐 Vulnerability Scan: {} vulnerabilities found", vuln_count);
+
+            Ok(format!("{} vulnerabilities", vuln_count))
+        }
+
+        Task::CryptoHash { algorithm, data } => {
+            let hash = hash_with_algorithm(algorithm, data.as_bytes())?;
+            let _ = writeln!(out, "🔐 Hash ({}): {}", algorithm, hash);
+            Ok(hash)
+        }
+
+        Task::MerkleRoot { leaves, algorithm } => {
+            let root = merkle_root(leaves, algorithm)?;
+            let _ = writeln!(out, "🌳 Merkle Root ({}, {} leaves): {}", algorithm, leaves.len(), root);
+            Ok(root)
+        }
+
+        Task::Loop { iterations, operation } => {
+            let mut val: u32 = 0;
+
+            match operation.as_str() {
+                "xor" => {
+                    for i in 0..*iterations {
+                        val = (val.wrapping_add(i)) ^ 0x12345678;
                     }
-                    _ => {
-                        for i in 0..*iterations {
-                            val = val.wrapping_add(i);
-                        }
+                }
+                _ => {
+                    for i in 0..*iterations {
+                        val = val.wrapping_add(i);
                     }
                 }
-                
-                println!("🔢 Loop Result ({} iterations): {}", iterations, val);
-                Ok(format!("{}", val))
-            }
-            
-            Task::Custom { name, args } => {
-                println!("⚡ Custom Task: {}", name);
-                println!("   Args: {:?}", args);
-                Ok(format!("Executed: {}", name))
             }
+
+            let _ = writeln!(out, "🔢 Loop Result ({} iterations): {}", iterations, val);
+            Ok(format!("{}", val))
+        }
+
+        Task::MineBlock { header, difficulty, max_nonce } => {
+            let (nonce, digest) = mine_block(header, *difficulty, *max_nonce)?;
+            let _ = writeln!(out, "⛏️  Mined block \"{}\": nonce={} hash={}", header, nonce, digest);
+            Ok(format!("nonce={} hash={}", nonce, digest))
+        }
+
+        Task::Custom { name, args } => {
+            let _ = writeln!(out, "⚡ Custom Task: {}", name);
+            let _ = writeln!(out, "   Args: {:?}", args);
+            Ok(format!("Executed: {}", name))
         }
     }
-    
-    pub fn get_results(&self) -> &[PipelineResult] {
-        &self.results
+}
+
+// Hashes `data` with the named algorithm. `keccak256` uses the original
+// Keccak padding (distinct from `sha3_256`'s NIST SHA-3 padding).
+fn hash_with_algorithm(algorithm: &str, data: &[u8]) -> TransRResult<String> {
+    use blake2::{Blake2b512, Digest as Blake2Digest};
+    use md5::Md5;
+    use sha2::{Digest as Sha2Digest, Sha256};
+    use sha3::{Digest as Sha3Digest, Keccak256, Sha3_256};
+
+    match algorithm {
+        "sha256" => {
+            let mut hasher = Sha256::new();
+            hasher.update(data);
+            Ok(format!("{:x}", hasher.finalize()))
+        }
+        "md5" => {
+            let mut hasher = Md5::new();
+            hasher.update(data);
+            Ok(format!("{:x}", hasher.finalize()))
+        }
+        "sha3_256" => {
+            let mut hasher = Sha3_256::new();
+            hasher.update(data);
+            Ok(format!("{:x}", hasher.finalize()))
+        }
+        "keccak256" => {
+            let mut hasher = Keccak256::new();
+            hasher.update(data);
+            Ok(format!("{:x}", hasher.finalize()))
+        }
+        "blake2b" => {
+            let mut hasher = Blake2b512::new();
+            hasher.update(data);
+            Ok(format!("{:x}", hasher.finalize()))
+        }
+        "blake3" => Ok(blake3::hash(data).to_hex().to_string()),
+        _ => Err(TransRError::CryptoError(format!("Unknown algorithm: {}", algorithm))),
+    }
+}
+
+// Builds a binary Merkle tree over `leaves` (hashing each leaf, then each
+// level's concatenated pairs, duplicating the last node on an odd count)
+// and returns the root's hex digest.
+fn merkle_root(leaves: &[String], algorithm: &str) -> TransRResult<String> {
+    if leaves.is_empty() {
+        return Err(TransRError::CryptoError("Cannot build a Merkle tree from zero leaves".to_string()));
+    }
+
+    let mut level = leaves.iter()
+        .map(|leaf| hash_with_algorithm(algorithm, leaf.as_bytes()))
+        .collect::<TransRResult<Vec<String>>>()?;
+
+    while level.len() > 1 {
+        let mut next_level = Vec::with_capacity((level.len() + 1) / 2);
+
+        for pair in level.chunks(2) {
+            let combined = if pair.len() == 2 {
+                format!("{}{}", pair[0], pair[1])
+            } else {
+                format!("{}{}", pair[0], pair[0])
+            };
+            next_level.push(hash_with_algorithm(algorithm, combined.as_bytes())?);
+        }
+
+        level = next_level;
     }
+
+    Ok(level.remove(0))
+}
+
+// Searches nonces `0..max_nonce` for one where
+// `keccak256(header || nonce.to_le_bytes())`, read as a big-endian 256-bit
+// integer, falls below `2^(256 - difficulty)` (i.e. has `difficulty`
+// leading zero bits). Returns the winning nonce and the hex digest.
+fn mine_block(header: &str, difficulty: u32, max_nonce: u64) -> TransRResult<(u64, String)> {
+    use num_bigint::{BigInt, Sign};
+    use sha3::{Digest, Keccak256};
+
+    let threshold = BigInt::from(1) << 256u32.saturating_sub(difficulty);
+    let header_bytes = header.as_bytes();
+
+    for nonce in 0..max_nonce {
+        let mut hasher = Keccak256::new();
+        hasher.update(header_bytes);
+        hasher.update(nonce.to_le_bytes());
+        let digest = hasher.finalize();
+
+        let hash_value = BigInt::from_bytes_be(Sign::Plus, &digest);
+        if hash_value < threshold {
+            return Ok((nonce, format!("{:x}", digest)));
+        }
+    }
+
+    Err(TransRError::CryptoError(format!(
+        "No nonce found meeting difficulty {} within {} attempts",
+        difficulty, max_nonce
+    )))
 }
 
 impl Default for TransRPipeline {
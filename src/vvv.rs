@@ -1,6 +1,14 @@
-use std::sync::atomic::{AtomicU8, Ordering};
+use crate::errhuman::{TransRError, TransRResult};
+use arc_swap::ArcSwap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{sync_channel, SyncSender, TrySendError};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-static VERBOSE_LEVEL: AtomicU8 = AtomicU8::new(0);
+// How many in-flight `Event`s the ring buffer holds before the producer
+// starts dropping instead of blocking the caller.
+const RING_BUFFER_CAPACITY: usize = 4096;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum VerboseLevel {
@@ -21,45 +29,257 @@ impl From<u8> for VerboseLevel {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventLevel {
+    Info,
+    Debug,
+    Trace,
+    Warn,
+    Error,
+    Success,
+}
+
+// A single log line, pushed onto the ring buffer by the fast (producer)
+// path and rendered later by the background drain thread.
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub level: EventLevel,
+    pub timestamp_ms: u64,
+    pub thread_id: String,
+    pub message: String,
+}
+
+fn render(event: &Event) -> String {
+    let tag = match event.level {
+        EventLevel::Info => "ℹ️  [INFO]",
+        EventLevel::Debug => "🔍 [DEBUG]",
+        EventLevel::Trace => "🔬 [TRACE]",
+        EventLevel::Warn => "⚠️  [WARN]",
+        EventLevel::Error => "❌ [ERROR]",
+        EventLevel::Success => "✅ [SUCCESS]",
+    };
+    format!("{} ({}) {}", tag, event.thread_id, event.message)
+}
+
+// Destination for drained events, so callers aren't stuck with `println!`
+// as the only option — file, stderr, or an in-memory buffer for tests.
+pub trait Sink: Send + Sync {
+    fn write(&self, event: &Event);
+}
+
+pub struct StdoutSink;
+impl Sink for StdoutSink {
+    fn write(&self, event: &Event) {
+        println!("{}", render(event));
+    }
+}
+
+pub struct StderrSink;
+impl Sink for StderrSink {
+    fn write(&self, event: &Event) {
+        eprintln!("{}", render(event));
+    }
+}
+
+pub struct FileSink {
+    file: std::sync::Mutex<std::fs::File>,
+}
+
+impl FileSink {
+    pub fn new(path: &str) -> TransRResult<Self> {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(TransRError::IoError)?;
+
+        Ok(FileSink { file: std::sync::Mutex::new(file) })
+    }
+}
+
+impl Sink for FileSink {
+    fn write(&self, event: &Event) {
+        use std::io::Write;
+        let mut file = self.file.lock().unwrap();
+        let _ = writeln!(file, "{}", render(event));
+    }
+}
+
+// Collects events instead of printing them, for tests that want to assert
+// on what was logged.
+pub struct MemorySink {
+    events: std::sync::Mutex<Vec<Event>>,
+}
+
+impl MemorySink {
+    pub fn new() -> Arc<Self> {
+        Arc::new(MemorySink { events: std::sync::Mutex::new(Vec::new()) })
+    }
+
+    pub fn events(&self) -> Vec<Event> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl Sink for MemorySink {
+    fn write(&self, event: &Event) {
+        self.events.lock().unwrap().push(event.clone());
+    }
+}
+
+struct Config {
+    level: VerboseLevel,
+    sinks: Vec<Arc<dyn Sink>>,
+}
+
+// The lock-free tracing backend: a bounded ring buffer feeds a single
+// background drain thread, and the active config (level + sinks) lives
+// behind an `ArcSwap` so readers and writers never block on each other.
+struct Backend {
+    sender: SyncSender<Event>,
+    config: Arc<ArcSwap<Config>>,
+    emitted: AtomicU64,
+    drained: Arc<AtomicU64>,
+    dropped: AtomicU64,
+}
+
+static BACKEND: OnceLock<Backend> = OnceLock::new();
+
+fn backend() -> &'static Backend {
+    BACKEND.get_or_init(|| {
+        let (sender, receiver) = sync_channel(RING_BUFFER_CAPACITY);
+        let config = Arc::new(ArcSwap::from_pointee(Config {
+            level: VerboseLevel::Silent,
+            sinks: vec![Arc::new(StdoutSink) as Arc<dyn Sink>],
+        }));
+        let drained = Arc::new(AtomicU64::new(0));
+
+        let thread_config = config.clone();
+        let thread_drained = drained.clone();
+
+        thread::spawn(move || {
+            while let Ok(event) = receiver.recv() {
+                let cfg = thread_config.load();
+                for sink in &cfg.sinks {
+                    sink.write(&event);
+                }
+                thread_drained.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        Backend {
+            sender,
+            config,
+            emitted: AtomicU64::new(0),
+            drained,
+            dropped: AtomicU64::new(0),
+        }
+    })
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+fn emit(level: EventLevel, message: &str) {
+    let backend = backend();
+    let event = Event {
+        level,
+        timestamp_ms: now_ms(),
+        thread_id: format!("{:?}", thread::current().id()),
+        message: message.to_string(),
+    };
+
+    // Never block the caller: a full buffer just increments the drop counter.
+    // `emitted` only counts events the drain thread will actually see, so
+    // `flush()`'s `drained < emitted` wait can't get stuck behind events that
+    // were dropped instead of sent.
+    match backend.sender.try_send(event) {
+        Ok(()) => {
+            backend.emitted.fetch_add(1, Ordering::Relaxed);
+        }
+        Err(TrySendError::Full(_)) | Err(TrySendError::Disconnected(_)) => {
+            backend.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
 pub struct Verbose;
 
 impl Verbose {
     pub fn set_level(level: u8) {
-        VERBOSE_LEVEL.store(level.min(3), Ordering::Relaxed);
+        let level = VerboseLevel::from(level.min(3));
+        let backend = backend();
+        let current = backend.config.load_full();
+        backend.config.store(Arc::new(Config {
+            level,
+            sinks: current.sinks.clone(),
+        }));
     }
-    
+
     pub fn get_level() -> VerboseLevel {
-        VERBOSE_LEVEL.load(Ordering::Relaxed).into()
+        backend().config.load().level
     }
-    
+
+    // Adds a sink without disturbing the ones already configured; the
+    // swapped-in config is picked up by the drain thread's next event.
+    pub fn add_sink(sink: Arc<dyn Sink>) {
+        let backend = backend();
+        let current = backend.config.load_full();
+        let mut sinks = current.sinks.clone();
+        sinks.push(sink);
+        backend.config.store(Arc::new(Config {
+            level: current.level,
+            sinks,
+        }));
+    }
+
+    // Count of events dropped because the ring buffer was full.
+    pub fn dropped_events() -> u64 {
+        backend().dropped.load(Ordering::Relaxed)
+    }
+
+    // Blocks until the drain thread has caught up with everything emitted
+    // so far. Callers use this before shutdown so buffered events aren't lost.
+    pub fn flush() {
+        let backend = backend();
+        let target = backend.emitted.load(Ordering::SeqCst);
+        while backend.drained.load(Ordering::SeqCst) < target {
+            thread::sleep(Duration::from_micros(100));
+        }
+    }
+
     pub fn info(msg: &str) {
-        if VERBOSE_LEVEL.load(Ordering::Relaxed) >= 1 {
-            println!("ℹ️  [INFO] {}", msg);
+        if Self::get_level() as u8 >= VerboseLevel::Info as u8 {
+            emit(EventLevel::Info, msg);
         }
     }
-    
+
     pub fn debug(msg: &str) {
-        if VERBOSE_LEVEL.load(Ordering::Relaxed) >= 2 {
-            println!("🔍 [DEBUG] {}", msg);
+        if Self::get_level() as u8 >= VerboseLevel::Debug as u8 {
+            emit(EventLevel::Debug, msg);
         }
     }
-    
+
     pub fn trace(msg: &str) {
-        if VERBOSE_LEVEL.load(Ordering::Relaxed) >= 3 {
-            println!("🔬 [TRACE] {}", msg);
+        if Self::get_level() as u8 >= VerboseLevel::Trace as u8 {
+            emit(EventLevel::Trace, msg);
         }
     }
-    
+
     pub fn error(msg: &str) {
-        println!("❌ [ERROR] {}", msg);
+        emit(EventLevel::Error, msg);
     }
-    
+
     pub fn success(msg: &str) {
-        println!("✅ [SUCCESS] {}", msg);
+        emit(EventLevel::Success, msg);
     }
-    
+
     pub fn warn(msg: &str) {
-        println!("⚠️  [WARN] {}", msg);
+        emit(EventLevel::Warn, msg);
     }
 }
 